@@ -0,0 +1,70 @@
+use thiserror::Error;
+
+use crate::polynomial::InterpolationError;
+
+/// Errors returned by the fallible (`checked_` / `try_`) APIs of the crate.
+///
+/// The panicking counterparts remain for callers that control their inputs;
+/// anything handling untrusted data (shares, ciphertexts, moduli) should use
+/// the variants returning this type instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum Error {
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("value is not invertible modulo the given modulus")]
+    NotInvertible,
+    #[error(transparent)]
+    Interpolation(#[from] InterpolationError),
+    #[error("threshold must be at least 1")]
+    ThresholdZero,
+    #[error("threshold cannot exceed the number of shares")]
+    ThresholdExceedsShares,
+    #[error("all shares must have the same length")]
+    ShareLengthMismatch,
+    #[error("share x-coordinate must be non-zero")]
+    ZeroShareX,
+    #[error("ciphertext length is not a multiple of the block size")]
+    InvalidCiphertextLength,
+    #[error("invalid padding")]
+    InvalidPadding,
+    #[error("counter space exhausted")]
+    CounterOverflow,
+    #[error("invalid nonce or IV length")]
+    InvalidNonceLength,
+    #[error("message exceeds the maximum length for this mode")]
+    MessageTooLong,
+    #[error("authentication failed")]
+    AuthenticationFailed,
+    #[error("key data length is invalid for key wrap")]
+    InvalidKeyDataLength,
+    #[error("data unit must be at least one block long")]
+    DataUnitTooShort,
+    #[error("invalid code parameters")]
+    InvalidCodeParameters,
+    #[error("input length does not match the code")]
+    CodeLengthMismatch,
+    #[error("erasure position is out of range or repeated")]
+    InvalidErasure,
+    #[error("too many errors or erasures to correct")]
+    Uncorrectable,
+    #[error("all shards must have the same length")]
+    ShardLengthMismatch,
+    #[error("not enough shards to reconstruct the data")]
+    TooFewShards,
+    #[error("matrix dimensions do not match")]
+    DimensionMismatch,
+    #[error("matrix is singular")]
+    SingularMatrix,
+    #[error("linear system has no solution")]
+    NoSolution,
+    #[error("invalid group parameters")]
+    InvalidGroupParameters,
+    #[error("secret must be smaller than the group order")]
+    SecretOutOfRange,
+    #[error("commitment is not in the prime-order subgroup")]
+    InvalidCommitment,
+    #[error("share does not match the dealer's commitments")]
+    InvalidShare,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
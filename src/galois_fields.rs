@@ -1,40 +1,23 @@
+pub mod gf2n;
+pub mod gf2_16;
+pub mod gf2_32;
+pub mod gf2_128;
+use gf2n::{gf2n_mul, gf2n_mul_ct, AES_POLY, RS_POLY};
+use crate::{Error, Result};
+
 /// Runtime GF(256) addition (XOR)
 pub(crate) fn gf256_add(a: u8, b: u8) -> u8 {a ^ b }
 pub fn call_add(a: u8, b: u8) -> u8 { gf256_add(a, b) }
 /// Same as add in GF(2^8)
 pub(crate) fn gf256_sub(a: u8, b: u8) -> u8 { a ^ b }
 pub fn call_sub(a: u8, b: u8) -> u8 { gf256_sub(a, b) }
-pub(crate) fn gf256_mul_ct(mut a: u8, mut b: u8) -> u8 {
-    let mut result = 0u8;
-    // We loop exactly 8 times (deterministic timing)
-    for _ in 0..8 {
-        // Mask: if the LSB of b is 1, mask is 0xFF, else 0x00
-        let mask = !((b & 1).wrapping_sub(1));
-        result ^= a & mask;
-
-        // Reduction step: if MSB of a is 1, we XOR with the polynomial
-        let carry = (a >> 7) & 1;
-        let poly_mask = !((carry).wrapping_sub(1));
-        a = (a << 1) ^ (poly_mask & 0x1b);
-
-        b >>= 1;
-    }
-    result
+pub(crate) fn gf256_mul_ct(a: u8, b: u8) -> u8 {
+    // Exactly 8 masked iterations (deterministic timing), reduced by AES_POLY
+    gf2n_mul_ct::<AES_POLY, 8>(a as u64, b as u64) as u8
 }pub fn call_mul_ct(a: u8, b: u8) -> u8 { gf256_mul_ct(a, b) }
-pub(crate) fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
-    let mut result = 0u8;
-    while b != 0 {
-        if b & 1 != 0 {
-            result ^= a;
-        }
-        let carry = a & 0x80;
-        a <<= 1;
-        if carry != 0 {
-            a ^= 0x1b; // irreducible polynomial x^8 + x^4 + x^3 + x + 1
-        }
-        b >>= 1;
-    }
-    result
+pub(crate) fn gf256_mul(a: u8, b: u8) -> u8 {
+    // irreducible polynomial x^8 + x^4 + x^3 + x + 1
+    gf2n_mul::<AES_POLY, 8>(a as u64, b as u64) as u8
 }pub fn call_mul(a: u8, b: u8) -> u8 { gf256_mul(a, b) }
 fn gf256_exp(mut base: u8, mut exp: u8) -> u8 {
     let mut result = 1u8;
@@ -59,6 +42,16 @@ pub(crate) fn gf256_div(a: u8, b: u8) -> u8 {
     gf256_mul(a, gf256_inv(b))
 }
 
+/// Fallible inverse for untrusted input: zero is an error rather than a panic.
+pub(crate) fn gf256_checked_inv(a: u8) -> Result<u8> {
+    if a == 0 { return Err(Error::DivisionByZero); }
+    Ok(gf256_exp(a, 254))
+}pub fn call_checked_inv(a: u8) -> Result<u8> { gf256_checked_inv(a) }
+
+pub(crate) fn gf256_checked_div(a: u8, b: u8) -> Result<u8> {
+    Ok(gf256_mul(a, gf256_checked_inv(b)?))
+}pub fn call_checked_div(a: u8, b: u8) -> Result<u8> { gf256_checked_div(a, b) }
+
 
 
 
@@ -83,45 +76,37 @@ pub struct GF256Tables {
     pub exp: [u8; 512],
 }
 
-pub const TABLES: GF256Tables = {
-    let mut log = [0u8; 256];
-    let mut exp = [0u8; 512];
-    let mut x = 1u8;
-    let mut i = 0;
-    
-    while i < 255 {
-        exp[i] = x;
-        exp[i + 255] = x;
-        log[x as usize] = i as u8;
-        
-        let carry = x & 0x80;
-        x = (x << 1) ^ (if carry != 0 { 0x1b } else { 0 });
-        i += 1;
-    }
-    
-    GF256Tables { log, exp }
-};
-pub fn generate_tables() -> ([u8; 256], [u8; 512]) {
-    let mut log = [0u8; 256];
-    let mut exp = [0u8; 512];
-    
-    let mut x = 1u8;
-    for i in 0..255 {
-        exp[i] = x;
-        exp[i + 255] = x; // Duplicate for overflow handling
-        log[x as usize] = i as u8;
-        
-        // Multiply by 2 (the generator)
-        let carry = x & 0x80;
-        x <<= 1;
-        if carry != 0 {
-            x ^= 0x1b; // The irreducible polynomial
+impl GF256Tables {
+    /// Builds log/exp tables for GF(2^8) reduced by `POLY`, walking the powers
+    /// of `generator`, which must be a primitive element of that field.
+    pub const fn generate<const POLY: u64>(generator: u8) -> Self {
+        let mut log = [0u8; 256];
+        let mut exp = [0u8; 512];
+        let mut x = 1u8;
+        let mut i = 0;
+
+        while i < 255 {
+            exp[i] = x;
+            exp[i + 255] = x;
+            log[x as usize] = i as u8;
+
+            x = gf2n_mul_ct::<POLY, 8>(x as u64, generator as u64) as u8;
+            i += 1;
         }
+
+        GF256Tables { log, exp }
     }
-    
+}
+
+/// AES field tables. 0x03 generates GF(2^8)* under 0x11B (0x02 only has order 51).
+pub const TABLES: GF256Tables = GF256Tables::generate::<AES_POLY>(0x03);
+/// Reed–Solomon field tables. 0x02 is primitive under 0x11D.
+pub const RS_TABLES: GF256Tables = GF256Tables::generate::<RS_POLY>(0x02);
+
+pub fn generate_tables() -> ([u8; 256], [u8; 512]) {
     // Note: log[0] is technically undefined (-infinity)
-    // We usually set it to 0 or 255 depending on the impl.
-    (log, exp)
+    // We leave it at 0; callers must special-case zero.
+    (TABLES.log, TABLES.exp)
 }
 pub fn is_valid_log_table(log: [u8; 256], exp: [u8; 512]) -> bool {
     for x in 1u16..=255 {
@@ -154,8 +139,7 @@ pub fn gf256_mul_fast(a: u8, b: u8) -> u8 {
     // Adding logs is equivalent to multiplying elements
     // We use the 512-length exp table to avoid a % 255
     // TABLES.exp[(l_a as usize) + (l_b as usize)]
-    let res = TABLES.exp[(l_a as usize) + (l_b as usize)];
-    res
+    TABLES.exp[(l_a as usize) + (l_b as usize)]
 }pub fn run_mul_opt(a: u8, b: u8) -> u8 { gf256_mul_fast(a, b) } // Calling the table-based version
 
 
@@ -222,6 +206,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gf256_checked_inv_and_div() {
+        assert_eq!(gf256_checked_inv(0), Err(Error::DivisionByZero));
+        assert_eq!(gf256_checked_div(7, 0), Err(Error::DivisionByZero));
+        for a in 1..=255 {
+            assert_eq!(gf256_checked_inv(a), Ok(gf256_inv(a)));
+            assert_eq!(gf256_checked_div(a, a), Ok(1));
+        }
+    }
+
     #[test]
     #[should_panic(expected = "cannot invert 0 in GF(256)")]
     fn test_gf256_inv_zero_should_panic() {
@@ -247,9 +241,9 @@ mod tests {
     #[test]
     // Proves that multiplication distributes over addition: a * (b + c) = (a * b) + (a * c)
     fn is_mul_distributive() {
-        let a = 13 as u8;
-        let b = 16 as u8;
-        let c = 73 as u8;
+        let a = 13u8;
+        let b = 16u8;
+        let c = 73u8;
     
         assert_eq!(
             gf256_mul(a, gf256_add(b, c)),
@@ -261,9 +255,9 @@ mod tests {
     // Proves that addition is associative: a + (b + c) = (a + b) + c
     // NOTE: Since addition is simple XOR, this is easy for Z3 to prove (XOR is naturally associative).
     fn is_add_associative() {
-        let a = 13 as u8;
-        let b = 16 as u8;
-        let c = 73 as u8;
+        let a = 13u8;
+        let b = 16u8;
+        let c = 73u8;
     
         assert_eq!(
             gf256_add(a, gf256_add(b, c)),
@@ -275,9 +269,9 @@ mod tests {
     // Proves that multiplication is associative: a * (b * c) = (a * b) * c
     // NOTE: This is complex due to the reduction step in gf256_mul.
     fn is_mul_associative() {
-        let a = 13 as u8;
-        let b = 16 as u8;
-        let c = 73 as u8;
+        let a = 13u8;
+        let b = 16u8;
+        let c = 73u8;
     
         assert_eq!(
             gf256_mul(a, gf256_mul(b, c)),
@@ -288,8 +282,8 @@ mod tests {
     #[test]
     // Proves that multiplication is commutative: a * b = b * a
     pub(crate) fn is_mul_commutative() {
-            let a = 13 as u8;
-        let b = 16 as u8;
+            let a = 13u8;
+        let b = 16u8;
     
         assert_eq!(
         gf256_mul(a, b),
@@ -297,6 +291,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tables_are_valid() {
+        assert!(is_valid_log_table(TABLES.log, TABLES.exp));
+        assert!(is_valid_log_table(RS_TABLES.log, RS_TABLES.exp));
+    }
+
+    #[test]
+    fn test_gf256_mul_fast_matches_mul() {
+        for a in 0..=255 {
+            for b in 0..=255 {
+                assert_eq!(gf256_mul_fast(a, b), gf256_mul(a, b), "a = {}, b = {}", a, b);
+            }
+        }
+    }
+
     #[test]
     pub(crate) fn test_gf256_add_runtime() {
         for a in 0u8..=255 {
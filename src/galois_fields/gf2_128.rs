@@ -0,0 +1,129 @@
+//! GF(2^128) arithmetic for the GCM and POLYVAL conventions.
+//!
+//! Both fields are handled by one constant-time multiply over the "natural"
+//! representation (bit i of the `u128` is the coefficient of x^i), which only
+//! differs in the low part of the reduction polynomial:
+//!
+//! * GCM uses x^128 + x^7 + x^2 + x + 1 with bit-reflected blocks, so a block
+//!   read big-endian is reversed bitwise before and after multiplying.
+//! * POLYVAL uses x^128 + x^127 + x^126 + x^121 + 1 with little-endian blocks,
+//!   which are already in natural order.
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod clmul;
+
+/// x^7 + x^2 + x + 1: what x^128 reduces to under the GCM polynomial.
+pub const GCM_REDUCTION: u128 = 0x87;
+/// x^127 + x^126 + x^121 + 1: what x^128 reduces to under the POLYVAL polynomial.
+pub const POLYVAL_REDUCTION: u128 = 0xC200_0000_0000_0000_0000_0000_0000_0001;
+/// x^-128 in the POLYVAL field, the correction factor in RFC 8452's `dot`.
+pub const POLYVAL_X_INV_128: u128 = 0x9204_0000_0000_0000_0000_0000_0000_0001;
+
+/// Constant-time multiply of natural-order elements: always 128 masked iterations.
+pub(crate) const fn gf2_128_mul_ct<const REDUCTION: u128>(mut a: u128, mut b: u128) -> u128 {
+    let mut result = 0u128;
+    let mut i = 0;
+    while i < 128 {
+        let mask = (b & 1).wrapping_neg();
+        result ^= a & mask;
+
+        // x^128 overflows out of the register; fold it back in as REDUCTION
+        let carry = a >> 127;
+        a = (a << 1) ^ (carry.wrapping_neg() & REDUCTION);
+
+        b >>= 1;
+        i += 1;
+    }
+    result
+}
+
+/// Multiply two GCM (bit-reflected, big-endian) blocks.
+pub(crate) const fn gcm_mul_ct(a: u128, b: u128) -> u128 {
+    gf2_128_mul_ct::<GCM_REDUCTION>(a.reverse_bits(), b.reverse_bits()).reverse_bits()
+}pub fn call_gcm_mul_ct(a: u128, b: u128) -> u128 { gcm_mul_ct(a, b) }
+
+/// Multiply two POLYVAL (natural-order, little-endian) elements.
+pub(crate) const fn polyval_mul_ct(a: u128, b: u128) -> u128 {
+    gf2_128_mul_ct::<POLYVAL_REDUCTION>(a, b)
+}pub fn call_polyval_mul_ct(a: u128, b: u128) -> u128 { polyval_mul_ct(a, b) }
+
+/// RFC 8452 `dot(a, b) = a * b * x^-128`.
+pub(crate) const fn polyval_dot_ct(a: u128, b: u128) -> u128 {
+    polyval_mul_ct(polyval_mul_ct(a, b), POLYVAL_X_INV_128)
+}pub fn call_polyval_dot_ct(a: u128, b: u128) -> u128 { polyval_dot_ct(a, b) }
+
+/// Multiply by x in the GCM convention (a right shift of the reflected block).
+pub(crate) const fn gcm_mul_x(a: u128) -> u128 {
+    let carry = a & 1;
+    (a >> 1) ^ (carry.wrapping_neg() & (0xE1 << 120))
+}pub fn call_gcm_mul_x(a: u128) -> u128 { gcm_mul_x(a) }
+
+/// Multiply by x in the natural representation: the "doubling" of CMAC
+/// subkeys (big-endian blocks) and XTS tweaks (little-endian blocks).
+pub(crate) const fn gf2_128_mul_x<const REDUCTION: u128>(a: u128) -> u128 {
+    let carry = a >> 127;
+    (a << 1) ^ (carry.wrapping_neg() & REDUCTION)
+}pub fn call_gf2_128_mul_x(a: u128) -> u128 { gf2_128_mul_x::<GCM_REDUCTION>(a) }
+
+/// Square-and-multiply exponentiation in the natural representation.
+const fn gf2_128_pow<const REDUCTION: u128>(mut base: u128, mut exp: u128) -> u128 {
+    let mut result = 1u128;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            result = gf2_128_mul_ct::<REDUCTION>(result, base);
+        }
+        base = gf2_128_mul_ct::<REDUCTION>(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Inversion through Fermat's little theorem: a^(2^128 - 2) = a^-1.
+pub(crate) const fn gf2_128_inv<const REDUCTION: u128>(a: u128) -> u128 {
+    assert!(a != 0, "cannot invert 0 in GF(2^128)");
+    gf2_128_pow::<REDUCTION>(a, u128::MAX - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polyval_x_inv_128() {
+        // x^127 * x = x^128, and x^128 * x^-128 = 1
+        let x_128 = polyval_mul_ct(1 << 127, 2);
+        assert_eq!(x_128, POLYVAL_REDUCTION);
+        assert_eq!(polyval_mul_ct(x_128, POLYVAL_X_INV_128), 1);
+        assert_eq!(gf2_128_inv::<POLYVAL_REDUCTION>(x_128), POLYVAL_X_INV_128);
+    }
+
+    #[test]
+    fn test_gcm_one_is_msb() {
+        // In the reflected convention the block 0x80 00 .. 00 is the unit
+        let one = 1u128 << 127;
+        let a = 0x66e9_4bd4_ef8a_2c3b_884c_fa59_ca34_2b2e;
+        assert_eq!(gcm_mul_ct(a, one), a);
+        assert_eq!(gcm_mul_ct(one, a), a);
+    }
+
+    #[test]
+    fn test_gcm_mul_x_matches_mul() {
+        let x = 1u128 << 126;
+        let a = 0x0388_dace_60b6_a392_f328_c2b9_71b2_fe78;
+        assert_eq!(gcm_mul_x(a), gcm_mul_ct(a, x));
+    }
+
+    #[test]
+    fn test_natural_mul_x_matches_mul() {
+        let a = 0xfbee_d618_3571_3366_7c85_e08f_7236_a8de;
+        assert_eq!(gf2_128_mul_x::<GCM_REDUCTION>(a), gf2_128_mul_ct::<GCM_REDUCTION>(a, 2));
+        assert_eq!(gf2_128_mul_x::<GCM_REDUCTION>(1 << 127), GCM_REDUCTION);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = 0x2562_9347_5892_4276_1d31_f826_ba4b_757b;
+        assert_eq!(gf2_128_mul_ct::<GCM_REDUCTION>(a, gf2_128_inv::<GCM_REDUCTION>(a)), 1);
+        assert_eq!(polyval_mul_ct(a, gf2_128_inv::<POLYVAL_REDUCTION>(a)), 1);
+    }
+}
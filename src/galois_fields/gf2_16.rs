@@ -0,0 +1,103 @@
+//! GF(2^16) arithmetic: a constant-time bitwise multiply plus log/exp tables.
+
+use std::sync::OnceLock;
+
+use super::gf2n::{gf2n_inv, gf2n_mul, gf2n_mul_ct};
+
+/// x^16 + x^12 + x^3 + x + 1, primitive so that `x` (0x0002) generates the group.
+pub const GF2_16_POLY: u64 = 0x1100B;
+
+/// Order of the multiplicative group, 2^16 - 1.
+const ORDER: usize = 65535;
+
+pub(crate) fn gf2_16_mul_ct(a: u16, b: u16) -> u16 {
+    // 16 masked iterations regardless of the operands
+    gf2n_mul_ct::<GF2_16_POLY, 16>(a as u64, b as u64) as u16
+}pub fn call_gf2_16_mul_ct(a: u16, b: u16) -> u16 { gf2_16_mul_ct(a, b) }
+pub(crate) fn gf2_16_mul(a: u16, b: u16) -> u16 {
+    gf2n_mul::<GF2_16_POLY, 16>(a as u64, b as u64) as u16
+}pub fn call_gf2_16_mul(a: u16, b: u16) -> u16 { gf2_16_mul(a, b) }
+pub(crate) fn gf2_16_inv(a: u16) -> u16 {
+    assert!(a != 0, "cannot invert 0 in GF(2^16)");
+    gf2n_inv::<GF2_16_POLY, 16>(a as u64) as u16
+}pub fn call_gf2_16_inv(a: u16) -> u16 { gf2_16_inv(a) }
+
+/// Log/exp tables for GF(2^16), the 16-bit counterpart of `GF256Tables`.
+///
+/// At 384 KiB they are too large for a `const`, so they are built on first
+/// use and shared through [`gf2_16_tables`].
+pub struct GF2_16Tables {
+    pub log: Vec<u16>,
+    pub exp: Vec<u16>,
+}
+
+impl GF2_16Tables {
+    pub fn generate() -> Self {
+        let mut log = vec![0u16; ORDER + 1];
+        let mut exp = vec![0u16; 2 * ORDER];
+        let mut x = 1u16;
+
+        for i in 0..ORDER {
+            exp[i] = x;
+            exp[i + ORDER] = x; // Duplicate for overflow handling
+            log[x as usize] = i as u16;
+            x = gf2_16_mul(x, 2);
+        }
+
+        GF2_16Tables { log, exp }
+    }
+}
+
+pub fn gf2_16_tables() -> &'static GF2_16Tables {
+    static TABLES: OnceLock<GF2_16Tables> = OnceLock::new();
+    TABLES.get_or_init(GF2_16Tables::generate)
+}
+
+/// Table-based multiply. Not constant time: table indices depend on the operands.
+pub fn gf2_16_mul_fast(a: u16, b: u16) -> u16 {
+    if a == 0 || b == 0 { return 0; }
+
+    let tables = gf2_16_tables();
+    let l_a = tables.log[a as usize] as usize;
+    let l_b = tables.log[b as usize] as usize;
+    tables.exp[l_a + l_b]
+}
+
+/// Table-based inverse: a^-1 = g^(order - log a).
+pub fn gf2_16_inv_fast(a: u16) -> u16 {
+    assert!(a != 0, "cannot invert 0 in GF(2^16)");
+    let tables = gf2_16_tables();
+    tables.exp[ORDER - tables.log[a as usize] as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_cover_the_group() {
+        let tables = gf2_16_tables();
+        for x in 1..=u16::MAX {
+            assert_eq!(tables.exp[tables.log[x as usize] as usize], x);
+        }
+    }
+
+    #[test]
+    fn test_mul_fast_matches_ct() {
+        for a in (0..=u16::MAX).step_by(251) {
+            for b in (0..=u16::MAX).step_by(257) {
+                assert_eq!(gf2_16_mul_fast(a, b), gf2_16_mul_ct(a, b), "a = {}, b = {}", a, b);
+                assert_eq!(gf2_16_mul(a, b), gf2_16_mul_ct(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        for a in (1..=u16::MAX).step_by(97) {
+            let inv = gf2_16_inv(a);
+            assert_eq!(gf2_16_mul_ct(a, inv), 1);
+            assert_eq!(gf2_16_inv_fast(a), inv);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! GF(2^32) arithmetic: a constant-time bitwise multiply plus split tables.
+//!
+//! Full log/exp tables would take 32 GiB, so the fast path instead precomputes
+//! the products of one fixed multiplier with every byte position (the
+//! "split table" technique used by erasure coding libraries).
+
+use super::gf2n::{gf2n_inv, gf2n_mul, gf2n_mul_ct};
+
+/// x^32 + x^22 + x^2 + x + 1, a primitive polynomial.
+pub const GF2_32_POLY: u64 = 0x1_0040_0007;
+
+pub(crate) fn gf2_32_mul_ct(a: u32, b: u32) -> u32 {
+    // 32 masked iterations regardless of the operands
+    gf2n_mul_ct::<GF2_32_POLY, 32>(a as u64, b as u64) as u32
+}pub fn call_gf2_32_mul_ct(a: u32, b: u32) -> u32 { gf2_32_mul_ct(a, b) }
+pub(crate) fn gf2_32_mul(a: u32, b: u32) -> u32 {
+    gf2n_mul::<GF2_32_POLY, 32>(a as u64, b as u64) as u32
+}pub fn call_gf2_32_mul(a: u32, b: u32) -> u32 { gf2_32_mul(a, b) }
+pub(crate) fn gf2_32_inv(a: u32) -> u32 {
+    assert!(a != 0, "cannot invert 0 in GF(2^32)");
+    gf2n_inv::<GF2_32_POLY, 32>(a as u64) as u32
+}pub fn call_gf2_32_inv(a: u32) -> u32 { gf2_32_inv(a) }
+
+/// Split multiplication table for a fixed multiplier `c`:
+/// `tables[i][x] = c * (x << 8i)`, so `c * b` is four lookups and three XORs.
+pub struct GF2_32SplitTable {
+    pub tables: [[u32; 256]; 4],
+}
+
+impl GF2_32SplitTable {
+    pub fn new(c: u32) -> Self {
+        let mut tables = [[0u32; 256]; 4];
+        for (i, table) in tables.iter_mut().enumerate() {
+            for (x, entry) in table.iter_mut().enumerate() {
+                *entry = gf2_32_mul(c, (x as u32) << (8 * i));
+            }
+        }
+        GF2_32SplitTable { tables }
+    }
+
+    /// Not constant time: table indices depend on `b`.
+    pub fn mul(&self, b: u32) -> u32 {
+        let [b0, b1, b2, b3] = b.to_le_bytes();
+        self.tables[0][b0 as usize]
+            ^ self.tables[1][b1 as usize]
+            ^ self.tables[2][b2 as usize]
+            ^ self.tables[3][b3 as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_table_matches_ct() {
+        let multipliers = [0u32, 1, 2, 0xDEAD_BEEF, 0x8000_0000, u32::MAX];
+        for &c in &multipliers {
+            let table = GF2_32SplitTable::new(c);
+            for b in (0..=u32::MAX).step_by(0x0101_0101 + 7) {
+                assert_eq!(table.mul(b), gf2_32_mul_ct(c, b), "c = {:#x}, b = {:#x}", c, b);
+                assert_eq!(gf2_32_mul(c, b), gf2_32_mul_ct(c, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        for a in (1..=u32::MAX).step_by(0x0123_4567) {
+            assert_eq!(gf2_32_mul_ct(a, gf2_32_inv(a)), 1, "a = {:#x}", a);
+        }
+    }
+
+    #[test]
+    fn test_reduction() {
+        // x^31 * x = x^32 = x^22 + x^2 + x + 1
+        assert_eq!(gf2_32_mul_ct(0x8000_0000, 2), 0x0040_0007);
+    }
+}
@@ -0,0 +1,118 @@
+//! Binary extension field arithmetic GF(2^N), parameterised by the reduction
+//! polynomial `POLY` (including the x^N term) and the degree `N`.
+//!
+//! Elements are carried in the low `N` bits of a `u64`, so `1 <= N <= 63`.
+
+/// x^8 + x^4 + x^3 + x + 1, the AES / Rijndael polynomial.
+pub const AES_POLY: u64 = 0x11B;
+/// x^8 + x^4 + x^3 + x^2 + 1, the polynomial used by most Reed–Solomon codes.
+pub const RS_POLY: u64 = 0x11D;
+
+/// Compile-time check that `POLY` has degree exactly `N` and fits in a `u64`.
+pub(crate) const fn check_params<const POLY: u64, const N: u32>() {
+    assert!(N >= 1 && N <= 63, "GF(2^N) requires 1 <= N <= 63");
+    assert!(POLY >> N == 1, "POLY must have degree exactly N");
+}
+
+/// Mask selecting the `N` low bits that hold a field element.
+pub const fn gf2n_mask<const N: u32>() -> u64 {
+    (1u64 << N) - 1
+}
+
+/// Constant-time multiplication: always `N` iterations, no data-dependent branches.
+pub(crate) const fn gf2n_mul_ct<const POLY: u64, const N: u32>(mut a: u64, mut b: u64) -> u64 {
+    let mut result = 0u64;
+    let mut i = 0;
+    while i < N {
+        // Mask: if the LSB of b is 1, mask is all ones, else 0
+        let mask = (b & 1).wrapping_neg();
+        result ^= a & mask;
+
+        // Reduction step: if bit N-1 of a is set, shifting overflows into x^N
+        let carry = (a >> (N - 1)) & 1;
+        a = (a << 1) ^ (carry.wrapping_neg() & POLY);
+
+        b >>= 1;
+        i += 1;
+    }
+    result
+}
+pub fn call_gf2n_mul_ct<const POLY: u64, const N: u32>(a: u64, b: u64) -> u64 { gf2n_mul_ct::<POLY, N>(a, b) }
+
+/// Variable-time multiplication, terminating as soon as `b` runs out of bits.
+pub(crate) const fn gf2n_mul<const POLY: u64, const N: u32>(mut a: u64, mut b: u64) -> u64 {
+    let mut result = 0u64;
+    while b != 0 {
+        if b & 1 != 0 {
+            result ^= a;
+        }
+        let carry = (a >> (N - 1)) & 1;
+        a <<= 1;
+        if carry != 0 {
+            a ^= POLY;
+        }
+        b >>= 1;
+    }
+    result
+}
+pub fn call_gf2n_mul<const POLY: u64, const N: u32>(a: u64, b: u64) -> u64 { gf2n_mul::<POLY, N>(a, b) }
+
+/// Square-and-multiply exponentiation built on the constant-time multiply.
+pub(crate) const fn gf2n_pow<const POLY: u64, const N: u32>(mut base: u64, mut exp: u64) -> u64 {
+    let mut result = 1u64;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            result = gf2n_mul_ct::<POLY, N>(result, base);
+        }
+        base = gf2n_mul_ct::<POLY, N>(base, base);
+        exp >>= 1;
+    }
+    result
+}
+pub fn call_gf2n_pow<const POLY: u64, const N: u32>(base: u64, exp: u64) -> u64 { gf2n_pow::<POLY, N>(base, exp) }
+
+/// Inversion through Fermat's little theorem: a^(2^N - 2) = a^-1.
+pub(crate) const fn gf2n_inv<const POLY: u64, const N: u32>(a: u64) -> u64 {
+    assert!(a != 0, "cannot invert 0 in GF(2^N)");
+    gf2n_pow::<POLY, N>(a, gf2n_mask::<N>() - 1)
+}
+pub fn call_gf2n_inv<const POLY: u64, const N: u32>(a: u64) -> u64 { gf2n_inv::<POLY, N>(a) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf2n_mul_matches_gf256() {
+        for a in 0..=255u64 {
+            for b in 0..=255u64 {
+                let expected = crate::galois_fields::call_mul(a as u8, b as u8) as u64;
+                assert_eq!(gf2n_mul::<AES_POLY, 8>(a, b), expected);
+                assert_eq!(gf2n_mul_ct::<AES_POLY, 8>(a, b), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gf2n_inverse_rs_poly() {
+        for a in 1..=255u64 {
+            let inv = gf2n_inv::<RS_POLY, 8>(a);
+            assert_eq!(gf2n_mul_ct::<RS_POLY, 8>(a, inv), 1, "a * inv(a) != 1 for a = {}", a);
+        }
+    }
+
+    #[test]
+    fn test_gf2n_small_field() {
+        // GF(2^4) with x^4 + x + 1: x * x^3 = x^4 = x + 1
+        assert_eq!(gf2n_mul::<0x13, 4>(0b0010, 0b1000), 0b0011);
+        for a in 1..16u64 {
+            assert_eq!(gf2n_mul_ct::<0x13, 4>(a, gf2n_inv::<0x13, 4>(a)), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot invert 0 in GF(2^N)")]
+    fn test_gf2n_inv_zero_should_panic() {
+        gf2n_inv::<AES_POLY, 8>(0);
+    }
+}
@@ -6,8 +6,13 @@
 
 
 
+pub mod error;
+pub use error::{Error, Result};
 pub mod galois_fields;
+pub mod matrix;
 pub mod polynomial;
 pub mod traits;
 pub mod types;
-pub mod uses;
\ No newline at end of file
+pub mod uses;
+#[cfg(test)]
+mod test_util;
\ No newline at end of file
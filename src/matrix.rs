@@ -0,0 +1,386 @@
+use std::ops::{Index, IndexMut, Mul};
+use zeroize::Zeroize;
+
+use crate::{traits::GaloisField, Error, Result};
+
+/// A dense matrix over any [`GaloisField`], stored row-major.
+///
+/// Used by the erasure coder; `mix_columns` is the product with the circulant
+/// matrix `circ(2, 3, 1, 1)` over GF(2^8).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Matrix<F> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<F>,
+}
+
+impl<F: GaloisField + PartialEq> Matrix<F> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix { rows, cols, entries: vec![F::zero(); rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = F::one();
+        }
+        m
+    }
+
+    /// Builds a matrix from equally long rows; panics if their lengths differ.
+    pub fn from_rows(rows: Vec<Vec<F>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|r| r.len() == cols), "matrix rows must have equal length");
+        Matrix { rows: rows.len(), cols, entries: rows.into_iter().flatten().collect() }
+    }
+
+    /// Row `i` is `[1, x_i, x_i^2, ..., x_i^(cols-1)]`; any `cols` rows at
+    /// distinct points are linearly independent.
+    pub fn vandermonde(points: &[F], cols: usize) -> Self {
+        Self::from_rows(
+            points
+                .iter()
+                .map(|x| {
+                    std::iter::successors(Some(F::one()), |p| Some(p.clone().run_mul(x.clone())))
+                        .take(cols)
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Entry `(i, j)` is `1 / (x_i - y_j)`; every square submatrix is
+    /// invertible when the `x_i` and `y_j` are all distinct.
+    ///
+    /// Fails with [`Error::DivisionByZero`] if some `x_i == y_j`.
+    pub fn cauchy(xs: &[F], ys: &[F]) -> Result<Self> {
+        let mut rows = Vec::with_capacity(xs.len());
+        for x in xs {
+            let mut row = Vec::with_capacity(ys.len());
+            for y in ys {
+                let d = x.clone().run_sub(y.clone());
+                if d == F::zero() {
+                    return Err(Error::DivisionByZero);
+                }
+                row.push(d.run_inv());
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[F] {
+        &self.entries[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// A new matrix made of the given rows of `self`, in order.
+    pub fn select_rows(&self, indices: &[usize]) -> Self {
+        Self::from_rows(indices.iter().map(|&i| self.row(i).to_vec()).collect())
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)].clone();
+            }
+        }
+        t
+    }
+
+    /// Like `*`, but reports mismatched dimensions instead of panicking.
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self> {
+        if self.cols != rhs.rows {
+            return Err(Error::DimensionMismatch);
+        }
+        let mut product = Self::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                product[(i, j)] = (0..self.cols).fold(F::zero(), |acc, k| {
+                    acc.run_add(self[(i, k)].clone().run_mul(rhs[(k, j)].clone()))
+                });
+            }
+        }
+        Ok(product)
+    }
+
+    /// The matrix-vector product `self * v`.
+    pub fn mul_vec(&self, v: &[F]) -> Result<Vec<F>> {
+        if v.len() != self.cols {
+            return Err(Error::DimensionMismatch);
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                self.row(i)
+                    .iter()
+                    .zip(v)
+                    .fold(F::zero(), |acc, (a, b)| acc.run_add(a.clone().run_mul(b.clone())))
+            })
+            .collect())
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut m = self.clone();
+        m.row_reduce(&mut Self::zeros(self.rows, 0)).len()
+    }
+
+    pub fn determinant(&self) -> Result<F> {
+        if !self.is_square() {
+            return Err(Error::DimensionMismatch);
+        }
+        // Forward elimination only: the determinant is the product of the
+        // pivots, negated once per row swap
+        let mut m = self.clone();
+        let mut det = F::one();
+        for col in 0..m.cols {
+            let Some(pivot) = (col..m.rows).find(|&r| m[(r, col)] != F::zero()) else {
+                return Ok(F::zero());
+            };
+            if pivot != col {
+                m.swap_rows(pivot, col);
+                det = F::zero().run_sub(det);
+            }
+            let p = m[(col, col)].clone();
+            det = det.run_mul(p.clone());
+            let p_inv = p.run_inv();
+            for r in col + 1..m.rows {
+                let factor = m[(r, col)].clone().run_mul(p_inv.clone());
+                m.sub_scaled_row(r, col, &factor);
+            }
+        }
+        Ok(det)
+    }
+
+    /// Gauss–Jordan inversion; fails with [`Error::SingularMatrix`] if the
+    /// matrix is not invertible.
+    pub fn inverse(&self) -> Result<Self> {
+        if !self.is_square() {
+            return Err(Error::DimensionMismatch);
+        }
+        let mut m = self.clone();
+        let mut inv = Self::identity(self.rows);
+        if m.row_reduce(&mut inv).len() < self.rows {
+            return Err(Error::SingularMatrix);
+        }
+        Ok(inv)
+    }
+
+    /// Finds an `x` with `self * x = b`, setting any free variables to zero.
+    ///
+    /// Fails with [`Error::NoSolution`] if the system is inconsistent.
+    pub fn solve(&self, b: &[F]) -> Result<Vec<F>> {
+        if b.len() != self.rows {
+            return Err(Error::DimensionMismatch);
+        }
+        let mut m = self.clone();
+        let mut rhs = Matrix { rows: self.rows, cols: 1, entries: b.to_vec() };
+        let pivots = m.row_reduce(&mut rhs);
+
+        if (pivots.len()..self.rows).any(|r| rhs[(r, 0)] != F::zero()) {
+            return Err(Error::NoSolution);
+        }
+        let mut x = vec![F::zero(); self.cols];
+        for (r, &c) in pivots.iter().enumerate() {
+            x[c] = rhs[(r, 0)].clone();
+        }
+        Ok(x)
+    }
+
+    /// Brings `self` to reduced row echelon form, applying the same row
+    /// operations to `other`; returns the pivot columns.
+    fn row_reduce(&mut self, other: &mut Self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let row = pivots.len();
+            let Some(pivot) = (row..self.rows).find(|&r| self[(r, col)] != F::zero()) else {
+                continue;
+            };
+            self.swap_rows(pivot, row);
+            other.swap_rows(pivot, row);
+
+            let scale = self[(row, col)].clone().run_inv();
+            self.scale_row(row, &scale);
+            other.scale_row(row, &scale);
+            for r in (0..self.rows).filter(|&r| r != row) {
+                let factor = self[(r, col)].clone();
+                if factor != F::zero() {
+                    self.sub_scaled_row(r, row, &factor);
+                    other.sub_scaled_row(r, row, &factor);
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols {
+            self.entries.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    fn scale_row(&mut self, r: usize, c: &F) {
+        for j in 0..self.cols {
+            self[(r, j)] = self[(r, j)].clone().run_mul(c.clone());
+        }
+    }
+
+    /// `row[target] -= factor * row[source]`
+    fn sub_scaled_row(&mut self, target: usize, source: usize, factor: &F) {
+        for j in 0..self.cols {
+            let delta = self[(source, j)].clone().run_mul(factor.clone());
+            self[(target, j)] = self[(target, j)].clone().run_sub(delta);
+        }
+    }
+}
+
+impl<F> Index<(usize, usize)> for Matrix<F> {
+    type Output = F;
+    fn index(&self, (i, j): (usize, usize)) -> &F {
+        assert!(i < self.rows && j < self.cols, "matrix index out of range");
+        &self.entries[i * self.cols + j]
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Matrix<F> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut F {
+        assert!(i < self.rows && j < self.cols, "matrix index out of range");
+        &mut self.entries[i * self.cols + j]
+    }
+}
+
+impl<F: GaloisField + PartialEq> Mul for &Matrix<F> {
+    type Output = Matrix<F>;
+    fn mul(self, rhs: Self) -> Matrix<F> {
+        self.checked_mul(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<F: GaloisField + PartialEq> Mul for Matrix<F> {
+    type Output = Matrix<F>;
+    fn mul(self, rhs: Self) -> Matrix<F> {
+        &self * &rhs
+    }
+}
+
+impl<F: Zeroize> Zeroize for Matrix<F> {
+    fn zeroize(&mut self) {
+        self.entries.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prime_modulus,
+        types::{FieldElement, PrimeFieldElement},
+        uses::aes::mix_columns,
+    };
+
+    prime_modulus!(F97 = 97u32.into());
+    type Fp = PrimeFieldElement<F97>;
+
+    fn fe(rows: &[&[u8]]) -> Matrix<FieldElement> {
+        Matrix::from_rows(rows.iter().map(|r| r.iter().map(|&b| FieldElement(b)).collect()).collect())
+    }
+
+    fn fp(rows: &[&[u64]]) -> Matrix<Fp> {
+        Matrix::from_rows(rows.iter().map(|r| r.iter().map(|&v| Fp::from_u64(v)).collect()).collect())
+    }
+
+    #[test]
+    fn test_mix_columns_is_a_matrix_product() {
+        let circulant = fe(&[&[2, 3, 1, 1], &[1, 2, 3, 1], &[1, 1, 2, 3], &[3, 1, 1, 2]]);
+        // FIPS-197 MixColumns example column: db 13 53 45 -> 8e 4d a1 bc
+        let column = [0xdb, 0x13, 0x53, 0x45].map(FieldElement);
+        let expected = [0x8e, 0x4d, 0xa1, 0xbc].map(FieldElement);
+        assert_eq!(circulant.mul_vec(&column).unwrap(), expected);
+
+        let mut state = [FieldElement(0); 16];
+        state[..4].copy_from_slice(&column);
+        mix_columns(&mut state);
+        assert_eq!(state[..4], expected);
+
+        let inverse = fe(&[&[14, 11, 13, 9], &[9, 14, 11, 13], &[13, 9, 14, 11], &[11, 13, 9, 14]]);
+        assert_eq!(circulant.inverse().unwrap(), inverse);
+        assert_eq!(&circulant * &inverse, Matrix::identity(4));
+    }
+
+    #[test]
+    fn test_determinant_and_rank_over_prime_field() {
+        let m = fp(&[&[2, 0, 1], &[1, 3, 2], &[1, 1, 1]]);
+        // 2(3 - 2) - 0 + 1(1 - 3) = 0
+        assert_eq!(m.determinant().unwrap(), Fp::from_u64(0));
+        assert_eq!(m.rank(), 2);
+        assert_eq!(m.inverse(), Err(Error::SingularMatrix));
+
+        // A row swap flips the sign: det [[0, 1], [1, 0]] = -1
+        assert_eq!(fp(&[&[0, 1], &[1, 0]]).determinant().unwrap(), Fp::from_u64(96));
+        assert_eq!(fp(&[&[4, 7], &[2, 6]]).determinant().unwrap(), Fp::from_u64(10));
+    }
+
+    #[test]
+    fn test_inverse_roundtrip_and_transpose() {
+        let m = fp(&[&[4, 7, 1], &[2, 6, 5], &[3, 3, 3]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(&m * &inv, Matrix::identity(3));
+        assert_eq!(&inv * &m, Matrix::identity(3));
+
+        let t = m.transpose();
+        assert_eq!(t[(0, 1)], m[(1, 0)]);
+        assert_eq!(t.transpose(), m);
+        assert_eq!(fp(&[&[1, 2, 3]]).transpose().rows(), 3);
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = fp(&[&[1, 1, 1], &[0, 2, 5], &[2, 5, 96]]);
+        let x = m.solve(&[6, 4, 27].map(Fp::from_u64)).unwrap();
+        assert_eq!(m.mul_vec(&x).unwrap(), [6, 4, 27].map(Fp::from_u64));
+
+        // Underdetermined but consistent, then inconsistent
+        let m = fp(&[&[1, 2], &[2, 4]]);
+        let x = m.solve(&[3, 6].map(Fp::from_u64)).unwrap();
+        assert_eq!(m.mul_vec(&x).unwrap(), [3, 6].map(Fp::from_u64));
+        assert_eq!(m.solve(&[3, 7].map(Fp::from_u64)), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn test_vandermonde_and_cauchy_submatrices_are_invertible() {
+        let points: Vec<FieldElement> = (0..8).map(FieldElement).collect();
+        let v = Matrix::vandermonde(&points, 3);
+        assert_eq!(v.row(2), [1, 2, 4].map(FieldElement));
+        for rows in [[0, 1, 2], [1, 4, 7], [5, 6, 3]] {
+            assert_eq!(v.select_rows(&rows).rank(), 3);
+        }
+
+        let xs: Vec<FieldElement> = (10..14).map(FieldElement).collect();
+        let c = Matrix::cauchy(&xs, &points[..4]).unwrap();
+        assert_ne!(c.determinant().unwrap(), FieldElement(0));
+        assert_eq!(c.select_rows(&[1, 3]).rank(), 2);
+        assert_eq!(Matrix::cauchy(&xs, &xs[..1]), Err(Error::DivisionByZero));
+    }
+
+    #[test]
+    fn test_dimension_errors() {
+        let a = fp(&[&[1, 2, 3]]);
+        assert_eq!(a.checked_mul(&a), Err(Error::DimensionMismatch));
+        assert_eq!(a.determinant(), Err(Error::DimensionMismatch));
+        assert_eq!(a.inverse(), Err(Error::DimensionMismatch));
+        assert_eq!(a.mul_vec(&[Fp::from_u64(1)]), Err(Error::DimensionMismatch));
+        assert_eq!(a.solve(&[]), Err(Error::DimensionMismatch));
+    }
+}
@@ -1,4 +1,8 @@
 pub mod evaluate_polynomial;
 pub mod extended_gcd;
+pub mod interpolation;
+pub use interpolation::{interpolate, interpolate_at, interpolate_newton, InterpolationError};
 pub mod lagrange_interpolate_at_zero;
-pub mod modinv;
\ No newline at end of file
+pub mod modinv;
+pub mod univariate;
+pub use univariate::Polynomial;
\ No newline at end of file
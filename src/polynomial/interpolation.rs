@@ -0,0 +1,175 @@
+use thiserror::Error;
+
+use crate::{polynomial::Polynomial, traits::GaloisField};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum InterpolationError {
+    #[error("no points to interpolate")]
+    NoPoints,
+    #[error("points {first} and {second} share the same x-coordinate")]
+    DuplicateX { first: usize, second: usize },
+}
+
+/// Rejects empty input and colliding x-coordinates, which would otherwise
+/// surface as a division by zero.
+fn check_points<F: GaloisField + PartialEq>(points: &[(F, F)]) -> Result<(), InterpolationError> {
+    if points.is_empty() {
+        return Err(InterpolationError::NoPoints);
+    }
+    for (first, (xi, _)) in points.iter().enumerate() {
+        if let Some(offset) = points[first + 1..].iter().position(|(xj, _)| xj == xi) {
+            return Err(InterpolationError::DuplicateX { first, second: first + 1 + offset });
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates the unique polynomial of degree `< points.len()` through
+/// `points` at `x`, without building its coefficients.
+pub fn interpolate_at<F: GaloisField + PartialEq>(
+    x: &F,
+    points: &[(F, F)]
+) -> Result<F, InterpolationError> {
+    check_points(points)?;
+
+    let mut result = F::zero();
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut num = F::one();
+        let mut den = F::one();
+
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i == j { continue; }
+            num = num.run_mul(x.clone().run_sub(xj.clone()));
+            den = den.run_mul(xi.clone().run_sub(xj.clone()));
+        }
+
+        // den != 0 because the x-coordinates were checked to be distinct
+        let li = num.run_mul(den.run_inv());
+        result = result.run_add(li.run_mul(yi.clone()));
+    }
+
+    Ok(result)
+}
+
+/// Recovers the coefficients of the interpolating polynomial with the
+/// Lagrange basis: `sum_i y_i * prod_{j != i} (X - x_j) / (x_i - x_j)`.
+pub fn interpolate<F: GaloisField + PartialEq>(
+    points: &[(F, F)]
+) -> Result<Polynomial<F>, InterpolationError> {
+    check_points(points)?;
+
+    let mut result = Polynomial::zero();
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let mut basis = Polynomial::one();
+        let mut den = F::one();
+
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i == j { continue; }
+            basis = &basis * &linear_factor(xj);
+            den = den.run_mul(xi.clone().run_sub(xj.clone()));
+        }
+
+        let scale = yi.clone().run_mul(den.run_inv());
+        result = &result + &basis.scalar_mul(&scale);
+    }
+
+    Ok(result)
+}
+
+/// Recovers the coefficients of the interpolating polynomial from Newton's
+/// divided differences, `c_0 + c_1 (X - x_0) + c_2 (X - x_0)(X - x_1) + ...`.
+///
+/// Needs O(n^2) field operations instead of the O(n^3) of [`interpolate`].
+pub fn interpolate_newton<F: GaloisField + PartialEq>(
+    points: &[(F, F)]
+) -> Result<Polynomial<F>, InterpolationError> {
+    check_points(points)?;
+
+    // Divided difference table, updated in place column by column
+    let mut coefficients: Vec<F> = points.iter().map(|(_, y)| y.clone()).collect();
+    for level in 1..points.len() {
+        for i in (level..points.len()).rev() {
+            let num = coefficients[i].clone().run_sub(coefficients[i - 1].clone());
+            let den = points[i].0.clone().run_sub(points[i - level].0.clone());
+            coefficients[i] = num.run_mul(den.run_inv());
+        }
+    }
+
+    // Expand the nested form from the innermost coefficient outwards
+    let mut result = Polynomial::zero();
+    for (c, (x, _)) in coefficients.into_iter().zip(points).rev() {
+        result = &(&result * &linear_factor(x)) + &Polynomial::constant(c);
+    }
+
+    Ok(result)
+}
+
+/// `X - root`
+fn linear_factor<F: GaloisField + PartialEq>(root: &F) -> Polynomial<F> {
+    Polynomial::new(vec![F::zero().run_sub(root.clone()), F::one()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        polynomial::lagrange_interpolate_at_zero::lagrange_interpolate_at_zero,
+        prime_modulus,
+        types::{FieldElement, PrimeFieldElement},
+    };
+    use num_bigint::BigUint;
+
+    prime_modulus!(F97 = BigUint::from(97u32));
+    type E = PrimeFieldElement<F97>;
+
+    fn sample<F: GaloisField + PartialEq>(p: &Polynomial<F>, xs: &[F]) -> Vec<(F, F)> {
+        xs.iter().map(|x| (x.clone(), p.evaluate(x))).collect()
+    }
+
+    #[test]
+    fn test_recovers_gf256_polynomial() {
+        let p = Polynomial::new([200u8, 5, 7, 1].map(FieldElement).to_vec());
+        let points = sample(&p, &[1u8, 2, 3, 250].map(FieldElement));
+
+        assert_eq!(interpolate(&points).unwrap(), p);
+        assert_eq!(interpolate_newton(&points).unwrap(), p);
+        for x in [0u8, 4, 99, 255].map(FieldElement) {
+            assert_eq!(interpolate_at(&x, &points).unwrap(), p.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn test_recovers_prime_field_polynomial() {
+        let p = Polynomial::new([17u64, 0, 3, 96, 44].map(E::from_u64).to_vec());
+        let points = sample(&p, &[5u64, 9, 11, 60, 2].map(E::from_u64));
+
+        assert_eq!(interpolate(&points).unwrap(), p);
+        assert_eq!(interpolate_newton(&points).unwrap(), p);
+        assert_eq!(interpolate_at(&E::zero(), &points).unwrap(), E::from_u64(17));
+    }
+
+    #[test]
+    fn test_matches_lagrange_interpolate_at_zero() {
+        let shares = [(1u8, 45u8), (2, 48), (7, 3)];
+        let points: Vec<_> = shares.iter().map(|&(x, y)| (FieldElement(x), FieldElement(y))).collect();
+        assert_eq!(
+            interpolate_at(&FieldElement(0), &points).unwrap().0,
+            lagrange_interpolate_at_zero(&shares)
+        );
+    }
+
+    #[test]
+    fn test_rejects_duplicate_x() {
+        let points = [(1u8, 10u8), (2, 20), (1, 30)].map(|(x, y)| (FieldElement(x), FieldElement(y)));
+        let err = InterpolationError::DuplicateX { first: 0, second: 2 };
+        assert_eq!(interpolate_at(&FieldElement(0), &points), Err(err));
+        assert_eq!(interpolate(&points), Err(err));
+        assert_eq!(interpolate_newton(&points), Err(err));
+    }
+
+    #[test]
+    fn test_rejects_empty_input() {
+        let points: [(FieldElement, FieldElement); 0] = [];
+        assert_eq!(interpolate(&points), Err(InterpolationError::NoPoints));
+    }
+}
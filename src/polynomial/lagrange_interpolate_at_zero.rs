@@ -1,16 +1,18 @@
-use crate::galois_fields::{gf256_add, gf256_div, gf256_mul, gf256_sub};
+use crate::{
+    galois_fields::{gf256_add, gf256_checked_div, gf256_div, gf256_mul, gf256_sub},
+    polynomial::InterpolationError,
+    Result,
+};
 
 pub fn lagrange_interpolate_at_zero(shares: &[(u8, u8)]) -> u8 {
     let mut secret = 0u8;
 
-    for i in 0..shares.len() {
-        let (xi, yi) = shares[i];
+    for (i, &(xi, yi)) in shares.iter().enumerate() {
         let mut num = 1u8;
         let mut den = 1u8;
 
-        for j in 0..shares.len() {
+        for (j, &(xj, _)) in shares.iter().enumerate() {
             if i == j { continue; }
-            let (xj, _) = shares[j];
             
             num = gf256_mul(num, xj);
             // In GF(256), sub is XOR, but using the function is better for the prover
@@ -25,6 +27,35 @@ pub fn lagrange_interpolate_at_zero(shares: &[(u8, u8)]) -> u8 {
     secret
 }
 
+/// Like [`lagrange_interpolate_at_zero`], but rejects an empty share list and
+/// duplicate x-coordinates instead of panicking inside `gf256_div`.
+pub fn try_lagrange_interpolate_at_zero(shares: &[(u8, u8)]) -> Result<u8> {
+    if shares.is_empty() {
+        return Err(InterpolationError::NoPoints.into());
+    }
+
+    let mut secret = 0u8;
+    for (i, &(xi, yi)) in shares.iter().enumerate() {
+        let mut num = 1u8;
+        let mut den = 1u8;
+
+        for (j, &(xj, _)) in shares.iter().enumerate() {
+            if i == j { continue; }
+            if xi == xj {
+                let (first, second) = (i.min(j), i.max(j));
+                return Err(InterpolationError::DuplicateX { first, second }.into());
+            }
+            num = gf256_mul(num, xj);
+            den = gf256_mul(den, gf256_sub(xj, xi));
+        }
+
+        let li = gf256_checked_div(num, den)?;
+        secret = gf256_add(secret, gf256_mul(li, yi));
+    }
+
+    Ok(secret)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +112,22 @@ mod tests {
         assert_eq!(result, 200);
     }
 
+    #[test]
+    fn test_try_lagrange_interpolate() {
+        use crate::Error;
+
+        let p = |x: u8| gf256_add(42, gf256_mul(3, x));
+        assert_eq!(try_lagrange_interpolate_at_zero(&[(1, p(1)), (2, p(2))]), Ok(42));
+        assert_eq!(
+            try_lagrange_interpolate_at_zero(&[(1, 5), (2, 6), (1, 7)]),
+            Err(Error::Interpolation(InterpolationError::DuplicateX { first: 0, second: 2 }))
+        );
+        assert_eq!(
+            try_lagrange_interpolate_at_zero(&[]),
+            Err(Error::Interpolation(InterpolationError::NoPoints))
+        );
+    }
+
     #[test]
     fn test_lagrange_interpolate_order_independence() {
         // P(x) = 0x + 55, so all shares should be (x, 55)
@@ -3,6 +3,8 @@ use num_traits::{One, Zero};
 use secrecy::{ExposeSecret, SecretBox};
 
 use crate::{
+    Error,
+    Result,
     polynomial::extended_gcd::extended_gcd,
     types::secure_types::{
         SecretBigUint,
@@ -27,6 +29,17 @@ pub fn modinv(
     Some(result.to_biguint().unwrap())
 }
 
+/// Like [`modinv`], but reports a non-invertible input as an [`Error`].
+pub fn try_modinv(
+    a: &BigUint,
+    m: &BigUint
+) -> Result<BigUint> {
+    if m.is_zero() {
+        return Err(Error::DivisionByZero);
+    }
+    modinv(a, m).ok_or(Error::NotInvertible)
+}
+
 pub fn secure_modinv(
     secret: SecretBigUint,
     m: &BigUint
@@ -48,7 +61,19 @@ pub fn secure_modinv(
     )))
 }
 
+/// Like [`secure_modinv`], but reports a non-invertible secret as an [`Error`].
+pub fn try_secure_modinv(
+    secret: SecretBigUint,
+    m: &BigUint
+) -> Result<SecretBigUint> {
+    if m.is_zero() {
+        return Err(Error::DivisionByZero);
+    }
+    secure_modinv(secret, m).ok_or(Error::NotInvertible)
+}
+
 #[cfg(test)]
+#[allow(clippy::items_after_test_module)]
 mod tests {
     use super::*;
     use num_bigint::ToBigUint;
@@ -68,6 +93,17 @@ mod tests {
         assert!(modinv(&a, &m).is_none());
     }
 
+    #[test]
+    fn test_try_modinv() {
+        let m = 12u32.to_biguint().unwrap();
+        assert_eq!(try_modinv(&5u32.to_biguint().unwrap(), &m), Ok(5u32.to_biguint().unwrap()));
+        assert_eq!(try_modinv(&6u32.to_biguint().unwrap(), &m), Err(Error::NotInvertible));
+        assert_eq!(try_modinv(&m, &BigUint::zero()), Err(Error::DivisionByZero));
+
+        let secret = SecretBox::new(Box::new(SecureBigUint(6u32.to_biguint().unwrap())));
+        assert!(matches!(try_secure_modinv(secret, &m), Err(Error::NotInvertible)));
+    }
+
     #[test]
     fn test_secure_modinv_basic() {
         let a = 3u32.to_biguint().unwrap();
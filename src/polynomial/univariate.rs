@@ -0,0 +1,269 @@
+use std::ops::{Add, Mul, Neg, Sub};
+use zeroize::Zeroize;
+
+use crate::traits::GaloisField;
+
+/// A univariate polynomial over any [`GaloisField`], shared by Shamir sharing,
+/// interpolation and coding code.
+///
+/// Coefficients are stored lowest degree first and kept normalized: the last
+/// coefficient is never zero, so the zero polynomial has no coefficients.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Polynomial<F> {
+    coefficients: Vec<F>,
+}
+
+impl<F: GaloisField + PartialEq> Polynomial<F> {
+    /// Builds `c[0] + c[1]x + ... + c[n]x^n`, dropping trailing zeros.
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let mut p = Polynomial { coefficients };
+        p.normalize();
+        p
+    }
+
+    pub fn zero() -> Self {
+        Polynomial { coefficients: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        Self::constant(F::one())
+    }
+
+    pub fn constant(c: F) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// `c * x^degree`
+    pub fn monomial(c: F, degree: usize) -> Self {
+        let mut coefficients = vec![F::zero(); degree + 1];
+        coefficients[degree] = c;
+        Self::new(coefficients)
+    }
+
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    pub fn into_coefficients(self) -> Vec<F> {
+        self.coefficients
+    }
+
+    /// Coefficient of `x^i`, zero beyond the degree.
+    pub fn coefficient(&self, i: usize) -> F {
+        self.coefficients.get(i).cloned().unwrap_or_else(F::zero)
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<&F> {
+        self.coefficients.last()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Strips trailing zero coefficients.
+    pub fn normalize(&mut self) {
+        let zero = F::zero();
+        while self.coefficients.last() == Some(&zero) {
+            self.coefficients.pop();
+        }
+    }
+
+    /// Horner evaluation at `x`.
+    pub fn evaluate(&self, x: &F) -> F {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, c| acc.run_mul(x.clone()).run_add(c.clone()))
+    }
+
+    pub fn scalar_mul(&self, c: &F) -> Self {
+        Self::new(self.coefficients.iter().map(|a| a.clone().run_mul(c.clone())).collect())
+    }
+
+    /// Euclidean division: returns `(q, r)` with `self = q * divisor + r` and
+    /// `deg r < deg divisor`.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some(divisor_degree) = divisor.degree() else {
+            panic!("Division by the zero polynomial");
+        };
+        let lead_inv = divisor.coefficients[divisor_degree].clone().run_inv();
+
+        let mut remainder = self.coefficients.clone();
+        let Some(quotient_len) = (remainder.len() + 1).checked_sub(divisor.coefficients.len()) else {
+            return (Self::zero(), self.clone());
+        };
+        let mut quotient = vec![F::zero(); quotient_len];
+
+        for i in (0..quotient_len).rev() {
+            let factor = remainder[i + divisor_degree].clone().run_mul(lead_inv.clone());
+            for (j, d) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone().run_sub(factor.clone().run_mul(d.clone()));
+            }
+            quotient[i] = factor;
+        }
+
+        remainder.truncate(divisor_degree);
+        (Self::new(quotient), Self::new(remainder))
+    }
+}
+
+impl<F: Zeroize> Zeroize for Polynomial<F> {
+    fn zeroize(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<F: GaloisField + PartialEq> Add for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn add(self, rhs: Self) -> Polynomial<F> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..len).map(|i| self.coefficient(i).run_add(rhs.coefficient(i))).collect())
+    }
+}
+
+impl<F: GaloisField + PartialEq> Sub for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn sub(self, rhs: Self) -> Polynomial<F> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Polynomial::new((0..len).map(|i| self.coefficient(i).run_sub(rhs.coefficient(i))).collect())
+    }
+}
+
+impl<F: GaloisField + PartialEq> Mul for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn mul(self, rhs: Self) -> Polynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut product = vec![F::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                product[i + j] = product[i + j].clone().run_add(a.clone().run_mul(b.clone()));
+            }
+        }
+        Polynomial::new(product)
+    }
+}
+
+impl<F: GaloisField + PartialEq> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn neg(self) -> Polynomial<F> {
+        &Polynomial::zero() - self
+    }
+}
+
+macro_rules! forward_owned_binop {
+    ($imp:ident, $method:ident) => {
+        impl<F: GaloisField + PartialEq> $imp for Polynomial<F> {
+            type Output = Polynomial<F>;
+            fn $method(self, rhs: Self) -> Polynomial<F> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_owned_binop!(Add, add);
+forward_owned_binop!(Sub, sub);
+forward_owned_binop!(Mul, mul);
+
+impl<F: GaloisField + PartialEq> Neg for Polynomial<F> {
+    type Output = Polynomial<F>;
+    fn neg(self) -> Polynomial<F> {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prime_modulus,
+        types::{FieldElement, PrimeFieldElement},
+    };
+    use num_bigint::BigUint;
+
+    prime_modulus!(F97 = BigUint::from(97u32));
+    type E = PrimeFieldElement<F97>;
+
+    fn gf(coefficients: &[u8]) -> Polynomial<FieldElement> {
+        Polynomial::new(coefficients.iter().map(|&c| FieldElement(c)).collect())
+    }
+
+    fn fp(coefficients: &[u64]) -> Polynomial<E> {
+        Polynomial::new(coefficients.iter().map(|&c| E::from_u64(c)).collect())
+    }
+
+    #[test]
+    fn test_normalization_and_degree() {
+        assert_eq!(gf(&[1, 2, 0, 0]).coefficients().len(), 2);
+        assert_eq!(gf(&[1, 2, 0, 0]).degree(), Some(1));
+        assert_eq!(gf(&[0, 0]).degree(), None);
+        assert!(gf(&[0]).is_zero());
+        assert_eq!(gf(&[5, 0, 7]).leading_coefficient(), Some(&FieldElement(7)));
+        assert_eq!(Polynomial::monomial(FieldElement(3), 4).degree(), Some(4));
+    }
+
+    #[test]
+    fn test_evaluate_prime_field() {
+        // 1 + 2x + 3x^2 at x = 2 is 17, matching evaluate_polynomial
+        let p = fp(&[1, 2, 3]);
+        assert_eq!(p.evaluate(&E::from_u64(2)), E::from_u64(17));
+    }
+
+    #[test]
+    fn test_ring_arithmetic_prime_field() {
+        let a = fp(&[1, 1]); // x + 1
+        let b = fp(&[96, 1]); // x - 1
+        assert_eq!(&a * &b, fp(&[96, 0, 1])); // x^2 - 1
+        assert_eq!(&a - &a, Polynomial::zero());
+        assert_eq!(&a + &b, fp(&[0, 2]));
+        assert_eq!(-a.clone(), fp(&[96, 96]));
+        assert_eq!(a.scalar_mul(&E::from_u64(3)), fp(&[3, 3]));
+    }
+
+    #[test]
+    fn test_add_cancels_in_characteristic_two() {
+        let a = gf(&[1, 2, 3]);
+        assert!((&a + &a).is_zero());
+        assert_eq!(&a - &a, &a + &a);
+    }
+
+    #[test]
+    fn test_div_rem_reconstructs() {
+        let a = gf(&[7, 0, 13, 200, 1, 99]);
+        let b = gf(&[3, 1, 42]);
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert_eq!(&(&q * &b) + &r, a);
+
+        let a = fp(&[5, 0, 3, 1]);
+        let b = fp(&[2, 1]);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(r.degree().unwrap_or(0), 0);
+        assert_eq!(&(&q * &b) + &r, a);
+        // Remainder theorem: a mod (x + 2) = a(-2)
+        assert_eq!(r.coefficient(0), a.evaluate(&E::from_u64(95)));
+    }
+
+    #[test]
+    fn test_div_rem_smaller_dividend() {
+        let a = gf(&[1, 2]);
+        let b = gf(&[1, 2, 3]);
+        let (q, r) = a.div_rem(&b);
+        assert!(q.is_zero());
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by the zero polynomial")]
+    fn test_div_by_zero_should_panic() {
+        gf(&[1]).div_rem(&Polynomial::zero());
+    }
+}
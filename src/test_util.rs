@@ -0,0 +1,16 @@
+//! Hex decoding shared by the test vectors across the crate.
+
+/// Decodes the concatenation of `blocks`, so long vectors can be split
+/// across lines.
+pub(crate) fn hex(blocks: &[&str]) -> Vec<u8> {
+    let s: String = blocks.concat();
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("test vectors are valid hex"))
+        .collect()
+}
+
+/// Decodes a fixed-size key or block.
+pub(crate) fn array<const N: usize>(s: &str) -> [u8; N] {
+    hex(&[s]).try_into().expect("test vector has the expected length")
+}
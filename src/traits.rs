@@ -1,9 +1,12 @@
-pub trait GaloisField: Sized + Copy {
+/// Field operations shared by every element type in the crate.
+///
+/// Elements are taken by value. Fixed-width binary fields are `Copy`; prime
+/// fields over `BigUint` are only `Clone`, so generic code clones explicitly.
+pub trait GaloisField: Sized + Clone {
     fn run_add(self, other: Self) -> Self;
     fn run_sub(self, other: Self) -> Self;
     fn run_mul(self, other: Self) -> Self;
     fn run_inv(self) -> Self;
-    const ZERO: Self;
-    const ONE: Self;
+    fn zero() -> Self;
+    fn one() -> Self;
 }
-
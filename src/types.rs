@@ -1,4 +1,10 @@
 pub mod newtypes;
-pub use newtypes::{FieldElement};
+pub use newtypes::{
+    FieldElement,
+    GF2n, AesField, RsField, GF2_16, GF2_32,
+    GF2_128Gcm, GF2_128Polyval,
+    PrimeFieldElement, PrimeModulus,
+    MontgomeryField, MontgomeryElement,
+};
 
 pub mod secure_types;
@@ -1,2 +1,10 @@
 pub mod field_element;
-pub use field_element::FieldElement;
\ No newline at end of file
+pub use field_element::FieldElement;
+pub mod gf2n;
+pub use gf2n::{GF2n, AesField, RsField, GF2_16, GF2_32};
+pub mod gf2_128;
+pub use gf2_128::{GF2_128Gcm, GF2_128Polyval};
+pub mod prime_field_element;
+pub use prime_field_element::{PrimeFieldElement, PrimeModulus};
+pub mod montgomery_field;
+pub use montgomery_field::{MontgomeryField, MontgomeryElement};
\ No newline at end of file
@@ -1,56 +1,67 @@
 use zeroize::Zeroize;
 use std::ops::{Add, Sub, Mul, Div};
 
-#[derive(Copy, Clone, PartialEq, Eq, Default, Zeroize)]
+use crate::types::newtypes::gf2n::AesField;
+use crate::Result;
+
+/// The AES instantiation of GF(2^8), stored as a bare `u8`.
+///
+/// A thin byte-sized wrapper over [`AesField`] (`GF2n<0x11B, 8>`): every
+/// operation converts to `AesField`, so both types share one (constant-time)
+/// arithmetic path. A `u8` is always in range, hence the public field.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Zeroize)]
 pub struct FieldElement(pub u8);
 
+impl FieldElement {
+    pub const ZERO: Self = FieldElement(0);
+    pub const ONE: Self = FieldElement(1);
+
+    /// Panics on zero; see [`FieldElement::checked_inv`].
+    pub fn inv(self) -> Self {
+        AesField::from(self).inv().into()
+    }
+
+    /// Like [`FieldElement::inv`], but returns an error for zero instead of panicking.
+    pub fn checked_inv(self) -> Result<Self> {
+        AesField::from(self).checked_inv().map(Self::from)
+    }
+
+    /// Like `/`, but returns an error for a zero divisor instead of panicking.
+    pub fn checked_div(self, rhs: Self) -> Result<Self> {
+        Ok(self * rhs.checked_inv()?)
+    }
+}
+
 // Now we implement the traits so we can use +, -, *, /
 impl Add for FieldElement {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
-        FieldElement(self.0 ^ rhs.0)
+        (AesField::from(self) + AesField::from(rhs)).into()
     }
 }
 
 impl Sub for FieldElement {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
-        self.add(rhs) // Subtraction is Addition in GF(2^8)
+        (AesField::from(self) - AesField::from(rhs)).into()
     }
 }
 
 impl Mul for FieldElement {
     type Output = Self;
 
+    /// Reduced by the AES Polynomial (x^8 + x^4 + x^3 + x + 1) in constant time.
     fn mul(self, rhs: Self) -> Self {
-        let mut a = self.0;
-        let mut b = rhs.0;
-        let mut p = 0u8; // The product
-
-        for _ in 0..8 {
-            if (b & 1) != 0 {
-                p ^= a; // Galois Addition (XOR)
-            }
-            let hi_bit_set = (a & 0x80) != 0;
-            a <<= 1;
-            if hi_bit_set {
-                a ^= 0x1B; // XOR with AES Polynomial (x^8 + x^4 + x^3 + x + 1)
-            }
-            b >>= 1;
-        }
-        FieldElement(p)
+        (AesField::from(self) * AesField::from(rhs)).into()
     }
 }
 
-
 impl Div for FieldElement {
     type Output = Self;
 
     #[inline]
     fn div(self, rhs: Self) -> Self {
         if rhs.0 == 0 {
-            // In a professional crate, you might panic here 
-            // or return a 'Zero' element depending on the spec.
             panic!("Division by zero in GF(256)");
         }
         // a / b is just a * inv(b)
@@ -58,16 +69,6 @@ impl Div for FieldElement {
     }
 }
 
-use crate::galois_fields::{call_exp};
-impl FieldElement {
-    pub fn inv(self) -> Self {
-        // Fermat implementation: a^(255-2)
-        // This is safe because 0 is handled in the div trait or here
-        if self.0 == 0 { panic!("0 has no inverse"); }
-        FieldElement(call_exp(self.0, 254))
-    }
-}
-
 use std::ops::BitXor;
 impl BitXor for FieldElement {
     type Output = Self;
@@ -80,16 +81,13 @@ impl BitXor for FieldElement {
 
 
 use crate::traits::GaloisField;
-use crate::galois_fields::gf256_mul_ct;
 impl GaloisField for FieldElement {
     fn run_add(self, other: Self) -> Self { self.add(other) }
     fn run_sub(self, other: Self) -> Self { self.sub(other) }
-    fn run_mul(self, other: Self) -> Self {
-        FieldElement(gf256_mul_ct(self.0, other.0))
-    }
+    fn run_mul(self, other: Self) -> Self { self.mul(other) }
     fn run_inv(self) -> Self { self.inv() }
-    const ZERO: Self = FieldElement(0);
-    const ONE: Self = FieldElement(1);
+    fn zero() -> Self { Self::ZERO }
+    fn one() -> Self { Self::ONE }
 }
 
 
@@ -98,4 +96,16 @@ impl From<u8> for FieldElement {
     fn from(b: u8) -> Self {
         FieldElement(b)
     }
-}
\ No newline at end of file
+}
+
+impl From<AesField> for FieldElement {
+    fn from(e: AesField) -> Self {
+        FieldElement(e.value() as u8)
+    }
+}
+
+impl From<FieldElement> for AesField {
+    fn from(e: FieldElement) -> Self {
+        AesField::new(e.0 as u64)
+    }
+}
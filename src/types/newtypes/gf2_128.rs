@@ -0,0 +1,159 @@
+use zeroize::Zeroize;
+use std::ops::{Add, Sub, Mul, Div, BitXor};
+
+use crate::galois_fields::gf2_128::{
+    gcm_mul_ct, gf2_128_inv, polyval_dot_ct, polyval_mul_ct, GCM_REDUCTION, POLYVAL_REDUCTION
+};
+use crate::traits::GaloisField;
+use crate::{Error, Result};
+
+/// GF(2^128) in the GCM convention: a 16-byte block read big-endian, where the
+/// most significant bit is the coefficient of x^0 (SP 800-38D, section 6.3).
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Zeroize)]
+pub struct GF2_128Gcm(pub u128);
+
+/// GF(2^128) in the POLYVAL convention: a 16-byte block read little-endian,
+/// where bit i is the coefficient of x^i (RFC 8452, section 3).
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Zeroize)]
+pub struct GF2_128Polyval(pub u128);
+
+impl GF2_128Gcm {
+    pub fn from_bytes(block: [u8; 16]) -> Self {
+        GF2_128Gcm(u128::from_be_bytes(block))
+    }
+
+    pub fn to_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn inv(self) -> Self {
+        if self.0 == 0 { panic!("0 has no inverse"); }
+        GF2_128Gcm(gf2_128_inv::<GCM_REDUCTION>(self.0.reverse_bits()).reverse_bits())
+    }
+}
+
+impl GF2_128Polyval {
+    pub fn from_bytes(block: [u8; 16]) -> Self {
+        GF2_128Polyval(u128::from_le_bytes(block))
+    }
+
+    pub fn to_bytes(self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn inv(self) -> Self {
+        if self.0 == 0 { panic!("0 has no inverse"); }
+        GF2_128Polyval(gf2_128_inv::<POLYVAL_REDUCTION>(self.0))
+    }
+
+    /// RFC 8452 `dot(a, b) = a * b * x^-128`, the POLYVAL hashing step.
+    pub fn dot(self, rhs: Self) -> Self {
+        GF2_128Polyval(polyval_dot_ct(self.0, rhs.0))
+    }
+}
+
+macro_rules! impl_gf2_128_ops {
+    ($t:ident, $mul:path, $one:expr) => {
+        impl $t {
+            pub const ZERO: Self = $t(0);
+            pub const ONE: Self = $t($one);
+
+            /// Like `inv`, but returns an error for zero instead of panicking.
+            pub fn checked_inv(self) -> Result<Self> {
+                if self.0 == 0 { return Err(Error::DivisionByZero); }
+                Ok(self.inv())
+            }
+
+            /// Like `/`, but returns an error for a zero divisor instead of panicking.
+            pub fn checked_div(self, rhs: Self) -> Result<Self> {
+                Ok(self * rhs.checked_inv()?)
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn add(self, rhs: Self) -> Self {
+                self ^ rhs
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn sub(self, rhs: Self) -> Self {
+                self ^ rhs // Subtraction is Addition in GF(2^128)
+            }
+        }
+
+        impl BitXor for $t {
+            type Output = Self;
+            fn bitxor(self, rhs: Self) -> Self {
+                $t(self.0 ^ rhs.0)
+            }
+        }
+
+        // Always constant time: there is no variable-time path at this width
+        impl Mul for $t {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                $t($mul(self.0, rhs.0))
+            }
+        }
+
+        impl Div for $t {
+            type Output = Self;
+
+            #[inline]
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                if rhs.0 == 0 {
+                    panic!("Division by zero in GF(2^128)");
+                }
+                self * rhs.inv()
+            }
+        }
+
+        impl GaloisField for $t {
+            fn run_add(self, other: Self) -> Self { self.add(other) }
+            fn run_sub(self, other: Self) -> Self { self.sub(other) }
+            fn run_mul(self, other: Self) -> Self { self.mul(other) }
+            fn run_inv(self) -> Self { self.inv() }
+            fn zero() -> Self { Self::ZERO }
+            fn one() -> Self { Self::ONE }
+        }
+    };
+}
+
+impl_gf2_128_ops!(GF2_128Gcm, gcm_mul_ct, 1 << 127);
+impl_gf2_128_ops!(GF2_128Polyval, polyval_mul_ct, 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::array;
+
+    #[test]
+    fn test_field_identities() {
+        let g = GF2_128Gcm::from_bytes(array("66e94bd4ef8a2c3b884cfa59ca342b2e"));
+        assert_eq!(g * GF2_128Gcm::ONE, g);
+        assert_eq!(g * g.inv(), GF2_128Gcm::ONE);
+        assert_eq!((g / g) + g, GF2_128Gcm::ONE + g);
+
+        let p = GF2_128Polyval::from_bytes(array("25629347589242761d31f826ba4b757b"));
+        assert_eq!(p * GF2_128Polyval::ONE, p);
+        assert_eq!(p * p.inv(), GF2_128Polyval::ONE);
+    }
+
+    #[test]
+    fn test_polyval_dot_rfc8452() {
+        // RFC 8452, Appendix A
+        let h = GF2_128Polyval::from_bytes(array("25629347589242761d31f826ba4b757b"));
+        let x1 = GF2_128Polyval::from_bytes(array("4f4f95668c83dfb6401762bb2d01a262"));
+        let x2 = GF2_128Polyval::from_bytes(array("d1a24ddd2721d006bbe45f20d3c9f362"));
+
+        let s = x1.dot(h);
+        let s = (s + x2).dot(h);
+        assert_eq!(s.to_bytes(), array("f7a3b47b846119fae5b7866cf5e5b77e"));
+    }
+}
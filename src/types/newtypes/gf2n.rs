@@ -0,0 +1,210 @@
+use zeroize::Zeroize;
+use std::ops::{Add, Sub, Mul, Div, BitXor};
+
+use crate::galois_fields::gf2n::{
+    check_params, gf2n_inv, gf2n_mask, gf2n_mul_ct, AES_POLY, RS_POLY
+};
+use crate::galois_fields::gf2_16::{gf2_16_inv_fast, gf2_16_mul_fast, GF2_16_POLY};
+use crate::galois_fields::gf2_32::{GF2_32SplitTable, GF2_32_POLY};
+use crate::traits::GaloisField;
+use crate::{Error, Result};
+
+/// An element of GF(2^N) reduced by `POLY` (the x^N term included).
+///
+/// The value lives in the low `N` bits of the `u64`. The field is private so
+/// every element goes through [`GF2n::new`], which rejects out-of-range values.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug, Zeroize)]
+pub struct GF2n<const POLY: u64, const N: u32>(u64);
+
+/// GF(2^8) with the AES polynomial, the generic twin of `FieldElement`.
+pub type AesField = GF2n<AES_POLY, 8>;
+/// GF(2^8) with 0x11D, as used by QR codes, CCSDS and most Reed–Solomon codecs.
+pub type RsField = GF2n<RS_POLY, 8>;
+/// GF(2^16), for Shamir sharing and erasure codes beyond 255 participants.
+pub type GF2_16 = GF2n<GF2_16_POLY, 16>;
+/// GF(2^32), for very wide erasure codes.
+pub type GF2_32 = GF2n<GF2_32_POLY, 32>;
+
+impl<const POLY: u64, const N: u32> GF2n<POLY, N> {
+    const PARAMS_OK: () = check_params::<POLY, N>();
+    /// Mask of the bits that may be set in a canonical element.
+    pub const MASK: u64 = gf2n_mask::<N>();
+    pub const ZERO: Self = GF2n(0);
+    pub const ONE: Self = GF2n(1);
+
+    pub const fn new(value: u64) -> Self {
+        let () = Self::PARAMS_OK;
+        assert!(value & !Self::MASK == 0, "value does not fit in GF(2^N)");
+        GF2n(value)
+    }
+
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn inv(self) -> Self {
+        if self.0 == 0 { panic!("0 has no inverse"); }
+        GF2n(gf2n_inv::<POLY, N>(self.0))
+    }
+
+    /// Like [`GF2n::inv`], but returns an error for zero instead of panicking.
+    pub fn checked_inv(self) -> Result<Self> {
+        if self.0 == 0 { return Err(Error::DivisionByZero); }
+        Ok(GF2n(gf2n_inv::<POLY, N>(self.0)))
+    }
+
+    /// Like `/`, but returns an error for a zero divisor instead of panicking.
+    pub fn checked_div(self, rhs: Self) -> Result<Self> {
+        Ok(self * rhs.checked_inv()?)
+    }
+}
+
+impl GF2_16 {
+    /// Log/exp table multiply; faster than `*` but not constant time.
+    pub fn mul_fast(self, rhs: Self) -> Self {
+        GF2n(gf2_16_mul_fast(self.0 as u16, rhs.0 as u16) as u64)
+    }
+
+    /// Log/exp table inverse; not constant time.
+    pub fn inv_fast(self) -> Self {
+        GF2n(gf2_16_inv_fast(self.0 as u16) as u64)
+    }
+}
+
+impl GF2_32 {
+    /// Precomputes the split table for repeated multiplication by `self`;
+    /// faster than `*` once the table is reused, but not constant time.
+    pub fn split_table(self) -> GF2_32SplitTable {
+        GF2_32SplitTable::new(self.0 as u32)
+    }
+}
+
+impl<const POLY: u64, const N: u32> Add for GF2n<POLY, N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        self ^ rhs
+    }
+}
+
+impl<const POLY: u64, const N: u32> Sub for GF2n<POLY, N> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        self ^ rhs // Subtraction is Addition in GF(2^N)
+    }
+}
+
+impl<const POLY: u64, const N: u32> BitXor for GF2n<POLY, N> {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        GF2n(self.0 ^ rhs.0)
+    }
+}
+
+impl<const POLY: u64, const N: u32> Mul for GF2n<POLY, N> {
+    type Output = Self;
+    /// Constant time; GF(2^16) and GF(2^32) also offer faster table paths.
+    fn mul(self, rhs: Self) -> Self {
+        GF2n(gf2n_mul_ct::<POLY, N>(self.0, rhs.0))
+    }
+}
+
+impl<const POLY: u64, const N: u32> Div for GF2n<POLY, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            panic!("Division by zero in GF(2^N)");
+        }
+        self * rhs.inv()
+    }
+}
+
+impl<const POLY: u64, const N: u32> GaloisField for GF2n<POLY, N> {
+    fn run_add(self, other: Self) -> Self { self.add(other) }
+    fn run_sub(self, other: Self) -> Self { self.sub(other) }
+    fn run_mul(self, other: Self) -> Self { self.mul(other) }
+    fn run_inv(self) -> Self { self.inv() }
+    fn zero() -> Self { Self::ZERO }
+    fn one() -> Self { Self::ONE }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{galois_fields::call_mul, types::FieldElement};
+
+    #[test]
+    fn test_aes_field_matches_field_element() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                let generic = AesField::new(a as u64) * AesField::new(b as u64);
+                let fe = FieldElement(a) * FieldElement(b);
+                assert_eq!(FieldElement::from(generic), fe);
+                assert_eq!(AesField::from(fe), generic);
+                // Against the independent variable-time multiply
+                assert_eq!(fe.0, call_mul(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rs_field_differs_from_aes_field() {
+        // x^7 * x = x^8, which reduces differently under 0x11B and 0x11D
+        assert_eq!((AesField::new(0x80) * AesField::new(0x02)).0, 0x1B);
+        assert_eq!((RsField::new(0x80) * RsField::new(0x02)).0, 0x1D);
+    }
+
+    #[test]
+    fn test_field_axioms_via_trait() {
+        check_field(RsField::new(0x53), RsField::new(0xCA));
+        check_field(GF2n::<0x13, 4>::new(0x7), GF2n::<0x13, 4>::new(0xA));
+    }
+
+    fn check_field<F: GaloisField + Copy + PartialEq + std::fmt::Debug>(a: F, b: F) {
+        assert_eq!(a.run_mul(a.run_inv()), F::one());
+        assert_eq!(a.run_add(b).run_sub(b), a);
+        assert_eq!(a.run_mul(F::one()), a);
+        assert_eq!(a.run_add(F::zero()), a);
+    }
+
+    #[test]
+    fn test_wide_fields_fast_paths() {
+        let a = GF2_16::new(0xBEEF);
+        let b = GF2_16::new(0x1234);
+        assert_eq!(a.mul_fast(b), a.run_mul(b));
+        assert_eq!(a.inv_fast(), a.run_inv());
+        check_field(a, b);
+
+        let c = GF2_32::new(0xDEAD_BEEF);
+        let d = GF2_32::new(0x0BAD_F00D);
+        assert_eq!(GF2n(c.split_table().mul(d.0 as u32) as u64), c * d);
+        check_field(c, d);
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(RsField::ZERO.checked_inv(), Err(Error::DivisionByZero));
+        assert_eq!(RsField::ONE.checked_div(RsField::ZERO), Err(Error::DivisionByZero));
+        assert_eq!(RsField::new(7).checked_div(RsField::new(7)), Ok(RsField::ONE));
+
+        assert_eq!(FieldElement(0).checked_inv(), Err(Error::DivisionByZero));
+        assert_eq!(FieldElement(9).checked_div(FieldElement(0)), Err(Error::DivisionByZero));
+        assert_eq!(FieldElement(0x53).checked_inv(), Ok(FieldElement(0xCA)));
+    }
+
+    #[test]
+    fn test_div_roundtrip() {
+        let a = RsField::new(200);
+        let b = RsField::new(17);
+        assert_eq!((a / b) * b, a);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in GF(2^N)")]
+    fn test_new_rejects_out_of_range() {
+        GF2n::<0x13, 4>::new(0x10);
+    }
+}
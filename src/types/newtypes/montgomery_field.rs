@@ -0,0 +1,337 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+/// A prime field GF(p) with `p < 2^(64 * LIMBS)`, using Montgomery arithmetic
+/// on fixed-width limbs.
+///
+/// Unlike `PrimeFieldElement`, every operation here runs in time independent
+/// of the element values: no branches or memory accesses depend on secrets,
+/// and inversion is Fermat exponentiation with a public exponent rather than
+/// a variable-time Euclid. Only the modulus is treated as public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryField<const LIMBS: usize> {
+    modulus: [u64; LIMBS],
+    /// -p^-1 mod 2^64
+    n0_inv: u64,
+    /// R^2 mod p with R = 2^(64 * LIMBS), used to enter Montgomery form
+    r2: [u64; LIMBS],
+    /// R mod p, the Montgomery form of one
+    one: [u64; LIMBS],
+}
+
+/// An element of a [`MontgomeryField`], stored in Montgomery form `a * R mod p`.
+///
+/// Elements carry no reference to their field; mixing elements of different
+/// fields is a logic error that is not detected.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Zeroize)]
+pub struct MontgomeryElement<const LIMBS: usize>(pub(crate) [u64; LIMBS]);
+
+impl<const LIMBS: usize> MontgomeryElement<LIMBS> {
+    /// Constant-time equality.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = self.0.iter().zip(other.0.iter()).fold(0u64, |acc, (a, b)| acc | (a ^ b));
+        diff == 0
+    }
+}
+
+impl<const LIMBS: usize> MontgomeryField<LIMBS> {
+    /// Sets up the field for an odd modulus `p > 1` that fits in `LIMBS` limbs.
+    ///
+    /// Primality is not checked, but [`MontgomeryField::inv`] is only correct
+    /// for prime moduli.
+    pub fn new(modulus: &BigUint) -> Option<Self> {
+        if LIMBS == 0 || modulus <= &BigUint::one() || !modulus.bit(0) {
+            return None;
+        }
+        let p = to_limbs::<LIMBS>(modulus)?;
+
+        // Newton iteration for p^-1 mod 2^64: each step doubles the correct bits
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p[0].wrapping_mul(inv)));
+        }
+
+        let r = BigUint::one() << (64 * LIMBS);
+        let one = to_limbs::<LIMBS>(&(&r % modulus))?;
+        let r2 = to_limbs::<LIMBS>(&((&r * &r) % modulus))?;
+
+        Some(MontgomeryField { modulus: p, n0_inv: inv.wrapping_neg(), r2, one })
+    }
+
+    pub fn modulus(&self) -> BigUint {
+        from_limbs(&self.modulus)
+    }
+
+    pub fn zero(&self) -> MontgomeryElement<LIMBS> {
+        MontgomeryElement([0u64; LIMBS])
+    }
+
+    pub fn one(&self) -> MontgomeryElement<LIMBS> {
+        MontgomeryElement(self.one)
+    }
+
+    /// Converts a canonical value `0 <= value < p` into Montgomery form.
+    pub fn from_biguint(&self, value: &BigUint) -> Option<MontgomeryElement<LIMBS>> {
+        if value >= &self.modulus() {
+            return None;
+        }
+        let limbs = to_limbs::<LIMBS>(value)?;
+        Some(MontgomeryElement(self.mont_mul(&limbs, &self.r2)))
+    }
+
+    pub fn from_u64(&self, value: u64) -> MontgomeryElement<LIMBS> {
+        let reduced = BigUint::from(value) % self.modulus();
+        self.from_biguint(&reduced).expect("reduced value should be canonical")
+    }
+
+    /// Leaves Montgomery form and returns the canonical value.
+    pub fn to_biguint(&self, a: &MontgomeryElement<LIMBS>) -> BigUint {
+        let mut unit = [0u64; LIMBS];
+        unit[0] = 1;
+        from_limbs(&self.mont_mul(&a.0, &unit))
+    }
+
+    pub fn add(&self, a: &MontgomeryElement<LIMBS>, b: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        let mut sum = [0u64; LIMBS];
+        let mut carry = 0u64;
+        for (i, s) in sum.iter_mut().enumerate() {
+            (*s, carry) = adc(a.0[i], b.0[i], carry);
+        }
+        MontgomeryElement(self.reduce_once(sum, carry))
+    }
+
+    pub fn sub(&self, a: &MontgomeryElement<LIMBS>, b: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        let mut diff = [0u64; LIMBS];
+        let mut borrow = 0u64;
+        for (i, d) in diff.iter_mut().enumerate() {
+            (*d, borrow) = sbb(a.0[i], b.0[i], borrow);
+        }
+
+        // Add p back if the subtraction wrapped
+        let mask = borrow.wrapping_neg();
+        let mut carry = 0u64;
+        for (d, &p) in diff.iter_mut().zip(self.modulus.iter()) {
+            (*d, carry) = adc(*d, p & mask, carry);
+        }
+        MontgomeryElement(diff)
+    }
+
+    pub fn neg(&self, a: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        self.sub(&self.zero(), a)
+    }
+
+    pub fn mul(&self, a: &MontgomeryElement<LIMBS>, b: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        MontgomeryElement(self.mont_mul(&a.0, &b.0))
+    }
+
+    pub fn square(&self, a: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        self.mul(a, a)
+    }
+
+    /// `a^exp` by a square-and-always-multiply ladder over every bit of `exp`.
+    ///
+    /// The exponent's bit length is visible in the timing; its bits are not.
+    /// Use [`MontgomeryField::pow_bits`] to hide the length of a secret exponent.
+    pub fn pow(&self, a: &MontgomeryElement<LIMBS>, exp: &BigUint) -> MontgomeryElement<LIMBS> {
+        self.pow_bits(a, exp, exp.bits())
+    }
+
+    /// Like [`MontgomeryField::pow`], but the ladder always runs over `bits`
+    /// bits, so every exponent below `2^bits` takes the same time.
+    ///
+    /// Panics if `exp` does not fit in `bits` bits.
+    pub fn pow_bits(&self, a: &MontgomeryElement<LIMBS>, exp: &BigUint, bits: u64) -> MontgomeryElement<LIMBS> {
+        assert!(exp.bits() <= bits, "exponent does not fit in {bits} bits");
+        // Fixed-length limbs, so reading a bit never branches on the exponent's
+        // length; allocated once at full size so no unwiped copy is left behind
+        let mut digits = vec![0u64; bits.div_ceil(64) as usize];
+        for (d, e) in digits.iter_mut().zip(exp.iter_u64_digits()) {
+            *d = e;
+        }
+
+        let mut result = self.one;
+        for i in (0..bits).rev() {
+            result = self.mont_mul(&result, &result);
+            let product = self.mont_mul(&result, &a.0);
+            let mask = ((digits[(i / 64) as usize] >> (i % 64)) & 1).wrapping_neg();
+            for (r, p) in result.iter_mut().zip(product.iter()) {
+                *r ^= (*r ^ p) & mask;
+            }
+        }
+        digits.zeroize();
+        MontgomeryElement(result)
+    }
+
+    /// Fermat inversion `a^(p - 2)`; maps zero to zero.
+    pub fn inv(&self, a: &MontgomeryElement<LIMBS>) -> MontgomeryElement<LIMBS> {
+        let exp = self.modulus() - 2u32;
+        self.pow(a, &exp)
+    }
+
+    pub fn is_zero(&self, a: &MontgomeryElement<LIMBS>) -> bool {
+        a.ct_eq(&self.zero())
+    }
+
+    /// CIOS Montgomery multiplication: returns `a * b * R^-1 mod p`.
+    fn mont_mul(&self, a: &[u64; LIMBS], b: &[u64; LIMBS]) -> [u64; LIMBS] {
+        let p = &self.modulus;
+        let mut t = [0u64; LIMBS];
+        let mut t_hi = 0u64;
+
+        for &b_i in b.iter() {
+            // t += a * b_i
+            let mut carry = 0u64;
+            for j in 0..LIMBS {
+                (t[j], carry) = mac(t[j], a[j], b_i, carry);
+            }
+            let (hi, hi_carry) = adc(t_hi, carry, 0);
+
+            // t = (t + m * p) / 2^64, where m makes the low limb vanish
+            let m = t[0].wrapping_mul(self.n0_inv);
+            let (_, mut carry) = mac(t[0], m, p[0], 0);
+            for j in 1..LIMBS {
+                (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+            }
+            let (top, top_carry) = adc(hi, carry, 0);
+            t[LIMBS - 1] = top;
+            t_hi = hi_carry + top_carry;
+        }
+
+        self.reduce_once(t, t_hi)
+    }
+
+    /// Subtracts p from `value + hi * 2^(64 * LIMBS)` when it is at least p.
+    fn reduce_once(&self, value: [u64; LIMBS], hi: u64) -> [u64; LIMBS] {
+        let mut reduced = [0u64; LIMBS];
+        let mut borrow = 0u64;
+        for i in 0..LIMBS {
+            (reduced[i], borrow) = sbb(value[i], self.modulus[i], borrow);
+        }
+
+        // Keep `reduced` when the high word absorbs the borrow
+        let (_, borrow) = sbb(hi, 0, borrow);
+        let keep_value = borrow.wrapping_neg();
+        let mut out = [0u64; LIMBS];
+        for i in 0..LIMBS {
+            out[i] = (value[i] & keep_value) | (reduced[i] & !keep_value);
+        }
+        out
+    }
+}
+
+/// `a + b + carry`, returning (sum, carry out).
+#[inline]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let wide = a as u128 + b as u128 + carry as u128;
+    (wide as u64, (wide >> 64) as u64)
+}
+
+/// `a - b - borrow`, returning (difference, borrow out as 0 or 1).
+#[inline]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let wide = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (wide as u64, (wide >> 127) as u64)
+}
+
+/// `acc + a * b + carry`, returning (low word, high word).
+#[inline]
+fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let wide = acc as u128 + (a as u128) * (b as u128) + carry as u128;
+    (wide as u64, (wide >> 64) as u64)
+}
+
+fn to_limbs<const LIMBS: usize>(value: &BigUint) -> Option<[u64; LIMBS]> {
+    let digits = value.to_u64_digits();
+    if digits.len() > LIMBS {
+        return None;
+    }
+    let mut limbs = [0u64; LIMBS];
+    limbs[..digits.len()].copy_from_slice(&digits);
+    Some(limbs)
+}
+
+fn from_limbs(limbs: &[u64]) -> BigUint {
+    limbs.iter().rev().fold(BigUint::zero(), |acc, &limb| (acc << 64u32) + limb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::RandBigInt;
+
+    fn p256() -> BigUint {
+        BigUint::parse_bytes(
+            b"ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_matches_biguint_p256() {
+        let p = p256();
+        let field = MontgomeryField::<4>::new(&p).unwrap();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..200 {
+            let a = rng.gen_biguint_below(&p);
+            let b = rng.gen_biguint_below(&p);
+            let ma = field.from_biguint(&a).unwrap();
+            let mb = field.from_biguint(&b).unwrap();
+
+            assert_eq!(field.to_biguint(&ma), a);
+            assert_eq!(field.to_biguint(&field.add(&ma, &mb)), (&a + &b) % &p);
+            assert_eq!(field.to_biguint(&field.sub(&ma, &mb)), (&a + &p - &b) % &p);
+            assert_eq!(field.to_biguint(&field.mul(&ma, &mb)), (&a * &b) % &p);
+            assert_eq!(field.to_biguint(&field.neg(&ma)), (&p - &a) % &p);
+        }
+    }
+
+    #[test]
+    fn test_edge_values() {
+        let p = p256();
+        let field = MontgomeryField::<4>::new(&p).unwrap();
+        let max = field.from_biguint(&(&p - 1u32)).unwrap();
+        let one = field.one();
+
+        assert!(field.add(&max, &one).ct_eq(&field.zero()));
+        assert_eq!(field.to_biguint(&field.mul(&max, &max)), BigUint::one());
+        assert!(field.is_zero(&field.sub(&one, &one)));
+    }
+
+    #[test]
+    fn test_inverse_single_limb() {
+        let p = BigUint::from(0xffff_ffff_ffff_ffc5u64); // largest 64-bit prime
+        let field = MontgomeryField::<1>::new(&p).unwrap();
+        for v in [1u64, 2, 3, 0xDEAD_BEEF, 0xffff_ffff_ffff_ffc4] {
+            let a = field.from_u64(v);
+            assert!(field.mul(&a, &field.inv(&a)).ct_eq(&field.one()), "v = {}", v);
+        }
+        assert!(field.is_zero(&field.inv(&field.zero())));
+    }
+
+    #[test]
+    fn test_pow_matches_modpow() {
+        let p = p256();
+        let field = MontgomeryField::<4>::new(&p).unwrap();
+        let a = BigUint::from(0x1234_5678u32);
+        let e = BigUint::from(0xFFFF_0001u32);
+        let ma = field.from_biguint(&a).unwrap();
+        assert_eq!(field.to_biguint(&field.pow(&ma, &e)), a.modpow(&e, &p));
+
+        // Leading zero bits only pad the ladder
+        for e in [BigUint::zero(), BigUint::one(), e, BigUint::from(u64::MAX) << 64] {
+            assert_eq!(field.to_biguint(&field.pow_bits(&ma, &e, 256)), a.modpow(&e, &p));
+        }
+    }
+
+    #[test]
+    fn test_rejects_bad_inputs() {
+        assert!(MontgomeryField::<1>::new(&BigUint::from(10u32)).is_none());
+        assert!(MontgomeryField::<1>::new(&BigUint::one()).is_none());
+        assert!(MontgomeryField::<1>::new(&p256()).is_none());
+
+        let field = MontgomeryField::<4>::new(&p256()).unwrap();
+        assert!(field.from_biguint(&p256()).is_none());
+    }
+}
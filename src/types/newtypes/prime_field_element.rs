@@ -0,0 +1,262 @@
+pub use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use zeroize::Zeroize;
+
+use crate::{
+    polynomial::modinv::{modinv, try_modinv},
+    traits::GaloisField,
+    Error, Result,
+};
+
+/// Supplies the modulus of a prime field at the type level.
+///
+/// Implement it on a zero-sized marker type, usually through
+/// [`prime_modulus!`](crate::prime_modulus). The modulus is trusted to be
+/// prime; it is not tested for primality.
+pub trait PrimeModulus: 'static {
+    fn modulus() -> &'static BigUint;
+}
+
+/// Declares a zero-sized [`PrimeModulus`] marker whose modulus is built once,
+/// on first use, from the given `BigUint` expression.
+///
+/// ```
+/// use honest::{prime_modulus, types::PrimeFieldElement};
+///
+/// prime_modulus!(pub F97 = 97u32.into());
+/// let a = PrimeFieldElement::<F97>::from_u64(50);
+/// assert_eq!((a.clone() + a).value(), &3u32.into());
+/// ```
+#[macro_export]
+macro_rules! prime_modulus {
+    ($vis:vis $name:ident = $modulus:expr) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::types::PrimeModulus for $name {
+            fn modulus() -> &'static $crate::types::newtypes::prime_field_element::BigUint {
+                static MODULUS: ::std::sync::OnceLock<$crate::types::newtypes::prime_field_element::BigUint> =
+                    ::std::sync::OnceLock::new();
+                MODULUS.get_or_init(|| $modulus)
+            }
+        }
+    };
+}
+
+/// An element of GF(p), always held in canonical form `0 <= value < p`.
+pub struct PrimeFieldElement<M: PrimeModulus> {
+    value: BigUint,
+    _modulus: PhantomData<M>,
+}
+
+impl<M: PrimeModulus> PrimeFieldElement<M> {
+    /// Wraps `value`, rejecting anything outside `[0, p)`.
+    pub fn new(value: BigUint) -> Option<Self> {
+        if &value >= M::modulus() {
+            return None;
+        }
+        Some(Self::from_canonical(value))
+    }
+
+    /// Wraps `value mod p`; use [`PrimeFieldElement::new`] to reject instead.
+    pub fn from_reduced(value: BigUint) -> Self {
+        Self::from_canonical(value % M::modulus())
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Self::from_reduced(BigUint::from(value))
+    }
+
+    pub fn modulus() -> &'static BigUint {
+        M::modulus()
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    pub fn into_value(self) -> BigUint {
+        self.value
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        Self::from_canonical(self.value.modpow(exp, M::modulus()))
+    }
+
+    pub fn inv(&self) -> Self {
+        if self.value.is_zero() { panic!("0 has no inverse"); }
+        let inv = modinv(&self.value, M::modulus()).expect("modulus should be prime");
+        Self::from_canonical(inv)
+    }
+
+    /// Like [`PrimeFieldElement::inv`], but returns an error for zero (or a
+    /// composite modulus) instead of panicking.
+    pub fn checked_inv(&self) -> Result<Self> {
+        if self.value.is_zero() { return Err(Error::DivisionByZero); }
+        try_modinv(&self.value, M::modulus()).map(Self::from_canonical)
+    }
+
+    /// Like `/`, but returns an error for a zero divisor instead of panicking.
+    pub fn checked_div(self, rhs: Self) -> Result<Self> {
+        Ok(self * rhs.checked_inv()?)
+    }
+
+    fn from_canonical(value: BigUint) -> Self {
+        PrimeFieldElement { value, _modulus: PhantomData }
+    }
+}
+
+impl<M: PrimeModulus> Clone for PrimeFieldElement<M> {
+    fn clone(&self) -> Self {
+        Self::from_canonical(self.value.clone())
+    }
+}
+
+impl<M: PrimeModulus> PartialEq for PrimeFieldElement<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<M: PrimeModulus> Eq for PrimeFieldElement<M> {}
+
+impl<M: PrimeModulus> fmt::Debug for PrimeFieldElement<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PrimeFieldElement({} mod {})", self.value, M::modulus())
+    }
+}
+
+impl<M: PrimeModulus> Zeroize for PrimeFieldElement<M> {
+    fn zeroize(&mut self) {
+        self.value = BigUint::zero();
+    }
+}
+
+impl<M: PrimeModulus> Add for PrimeFieldElement<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.value + rhs.value;
+        let p = M::modulus();
+        Self::from_canonical(if &sum >= p { sum - p } else { sum })
+    }
+}
+
+impl<M: PrimeModulus> Sub for PrimeFieldElement<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        if self.value >= rhs.value {
+            Self::from_canonical(self.value - rhs.value)
+        } else {
+            Self::from_canonical(M::modulus() - (rhs.value - self.value))
+        }
+    }
+}
+
+impl<M: PrimeModulus> Neg for PrimeFieldElement<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        if self.value.is_zero() {
+            self
+        } else {
+            Self::from_canonical(M::modulus() - self.value)
+        }
+    }
+}
+
+impl<M: PrimeModulus> Mul for PrimeFieldElement<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_canonical((self.value * rhs.value) % M::modulus())
+    }
+}
+
+impl<M: PrimeModulus> Div for PrimeFieldElement<M> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.value.is_zero() {
+            panic!("Division by zero in GF(p)");
+        }
+        self * rhs.inv()
+    }
+}
+
+impl<M: PrimeModulus> GaloisField for PrimeFieldElement<M> {
+    fn run_add(self, other: Self) -> Self { self.add(other) }
+    fn run_sub(self, other: Self) -> Self { self.sub(other) }
+    fn run_mul(self, other: Self) -> Self { self.mul(other) }
+    fn run_inv(self) -> Self { self.inv() }
+    fn zero() -> Self { Self::from_canonical(BigUint::zero()) }
+    fn one() -> Self { Self::from_canonical(BigUint::one()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    prime_modulus!(F97 = BigUint::from(97u32));
+    // 2^127 - 1, a Mersenne prime
+    prime_modulus!(M127 = (BigUint::one() << 127u32) - 1u32);
+
+    type E = PrimeFieldElement<F97>;
+
+    #[test]
+    fn test_rejects_non_canonical() {
+        assert!(E::new(BigUint::from(96u32)).is_some());
+        assert!(E::new(BigUint::from(97u32)).is_none());
+        assert!(E::new(BigUint::from(1000u32)).is_none());
+        assert_eq!(E::from_u64(1000), E::from_u64(1000 % 97));
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = E::from_u64(60);
+        let b = E::from_u64(50);
+        assert_eq!(a.clone() + b.clone(), E::from_u64(13));
+        assert_eq!(b.clone() - a.clone(), E::from_u64(87));
+        assert_eq!(-a.clone(), E::from_u64(37));
+        assert_eq!(-E::zero(), E::zero());
+        assert_eq!(a.clone() * b.clone(), E::from_u64(3000 % 97));
+        assert_eq!((a.clone() / b.clone()) * b, a);
+    }
+
+    #[test]
+    fn test_every_inverse() {
+        for v in 1..97 {
+            let a = E::from_u64(v);
+            assert_eq!(a.clone().run_mul(a.run_inv()), E::one());
+        }
+    }
+
+    #[test]
+    fn test_fermat_large_prime() {
+        let a = PrimeFieldElement::<M127>::from_u64(0xDEAD_BEEF);
+        let p_minus_1 = M127::modulus() - 1u32;
+        assert_eq!(a.pow(&p_minus_1), PrimeFieldElement::one());
+        assert_eq!(a.clone() * a.inv(), PrimeFieldElement::one());
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(E::zero().checked_inv(), Err(Error::DivisionByZero));
+        assert_eq!(E::one().checked_div(E::zero()), Err(Error::DivisionByZero));
+        assert_eq!(E::from_u64(6).checked_div(E::from_u64(3)), Ok(E::from_u64(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "0 has no inverse")]
+    fn test_inv_zero_should_panic() {
+        E::zero().inv();
+    }
+}
@@ -1,2 +1,5 @@
 pub mod aes;
-pub mod shamir;
\ No newline at end of file
+pub mod erasure;
+pub mod reed_solomon;
+pub mod shamir;
+pub mod universal_hash;
\ No newline at end of file
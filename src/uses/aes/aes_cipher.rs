@@ -0,0 +1,152 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::uses::aes::modes::BlockCipher;
+
+macro_rules! aes_cipher {
+    ($(#[$doc:meta])* $name:ident, $key_len:literal) => {
+        $(#[$doc])*
+        ///
+        /// The key schedule runs once in `new`. Blocks go through AES-NI when
+        /// the CPU supports it, otherwise the portable code ([`BitslicedAes`]
+        /// with the `bitsliced` feature), exactly as in the modes. The round
+        /// keys are wiped when the value is dropped.
+        ///
+        /// [`BitslicedAes`]: crate::uses::aes::BitslicedAes
+        #[derive(Clone, Zeroize, ZeroizeOnDrop)]
+        pub struct $name {
+            pub(super) cipher: BlockCipher,
+        }
+
+        impl $name {
+            pub fn new(key: [u8; $key_len]) -> Self {
+                $name { cipher: BlockCipher::new(key) }
+            }
+
+            pub fn encrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+                self.cipher.encrypt(block)
+            }
+
+            pub fn decrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+                self.cipher.decrypt(block)
+            }
+
+            /// Encrypts each block in place (ECB over the slice).
+            pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+                self.cipher.encrypt_blocks(blocks);
+            }
+
+            /// Decrypts each block in place.
+            pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+                self.cipher.decrypt_blocks(blocks);
+            }
+        }
+    };
+}
+
+aes_cipher!(
+    /// AES-128 with a pre-expanded key.
+    Aes128, 16
+);
+aes_cipher!(
+    /// AES-192 with a pre-expanded key.
+    Aes192, 24
+);
+aes_cipher!(
+    /// AES-256 with a pre-expanded key.
+    Aes256, 32
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_arch = "x86_64")]
+    use crate::uses::aes::AesNi;
+    use crate::uses::aes::{
+        aes_decrypt,
+        aes_encrypt,
+        modes::test_util::for_each_backend,
+        BitslicedAes,
+    };
+    use rand::Rng;
+
+    // FIPS-197, Appendix C
+    const PLAINTEXT: u128 = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff;
+
+    fn key<const N: usize>() -> [u8; N] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    #[test]
+    fn test_fips197_appendix_c() {
+        let p = PLAINTEXT.to_be_bytes();
+        let cases = [
+            (Aes128::new(key()).encrypt_block(p), 0x69c4_e0d8_6a7b_0430_d8cd_b780_70b4_c55au128),
+            (Aes192::new(key()).encrypt_block(p), 0xdda9_7ca4_864c_dfe0_6eaf_70a0_ec0d_7191),
+            (Aes256::new(key()).encrypt_block(p), 0x8ea2_b7ca_5167_45bf_eafc_4990_4b49_6089),
+        ];
+        for (c, expected) in cases {
+            assert_eq!(u128::from_be_bytes(c), expected);
+        }
+        assert_eq!(Aes256::new(key()).decrypt_block(cases[2].1.to_be_bytes()), p);
+    }
+
+    #[test]
+    fn test_blocks_match_single_block_functions() {
+        for_each_backend(|| {
+            let aes = Aes128::new([7u8; 16]);
+            let mut blocks: Vec<[u8; 16]> = (0..11u8).map(|i| [i; 16]).collect();
+            let original = blocks.clone();
+
+            aes.encrypt_blocks(&mut blocks);
+            for (c, p) in blocks.iter().zip(&original) {
+                assert_eq!(*c, aes_encrypt(*p, [7u8; 16]));
+                assert_eq!(aes.decrypt_block(*c), aes_decrypt(*c, [7u8; 16]));
+            }
+            aes.decrypt_blocks(&mut blocks);
+            assert_eq!(blocks, original);
+        });
+    }
+
+    #[test]
+    fn test_default_backend_matches_bitsliced_and_aesni() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..32 {
+            let (k16, k24, k32): ([u8; 16], [u8; 24], [u8; 32]) = (rng.r#gen(), rng.r#gen(), rng.r#gen());
+            let block: [u8; 16] = rng.r#gen();
+            let ours = [
+                (Aes128::new(k16).encrypt_block(block), BitslicedAes::new(k16).encrypt_block(block)),
+                (Aes192::new(k24).encrypt_block(block), BitslicedAes::new(k24).encrypt_block(block)),
+                (Aes256::new(k32).encrypt_block(block), BitslicedAes::new(k32).encrypt_block(block)),
+            ];
+            for (aes, bitsliced) in ours {
+                assert_eq!(aes, bitsliced);
+            }
+            assert_eq!(Aes256::new(k32).decrypt_block(ours[2].0), block);
+
+            #[cfg(target_arch = "x86_64")]
+            if let Some(aesni) = AesNi::new(k16) {
+                assert_eq!(Aes128::new(k16).encrypt_block(block), aesni.encrypt_block(block));
+                assert_eq!(Aes128::new(k16).decrypt_block(block), aesni.decrypt_block(block));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zeroize_clears_round_keys() {
+        for_each_backend(|| {
+            let mut aes = Aes256::new([0xffu8; 32]);
+            assert!(!aes.cipher.keys_are_zero());
+            aes.zeroize();
+            assert!(aes.cipher.keys_are_zero());
+
+            // The keys are wiped in place, so the cipher still works (under
+            // an all-zero schedule) rather than panicking
+            let c = aes.encrypt_block([0x5a; 16]);
+            assert_eq!(aes.decrypt_block(c), [0x5a; 16]);
+            let mut blocks = [[1u8; 16]; 9];
+            aes.encrypt_blocks(&mut blocks);
+            aes.decrypt_blocks(&mut blocks);
+            assert_eq!(blocks, [[1u8; 16]; 9]);
+        });
+    }
+}
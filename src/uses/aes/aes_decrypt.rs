@@ -0,0 +1,144 @@
+use crate::{
+    types::FieldElement,
+    uses::aes::{aes_encrypt::add_round_key, aes_inv_round, modes::BlockCipher},
+};
+
+/// Decrypts one block under a 16, 24 or 32 byte key (AES-128/192/256).
+///
+/// Dispatches like [`aes_encrypt`](crate::uses::aes::aes_encrypt).
+pub fn aes_decrypt<const N: usize>(ciphertext: [u8; 16], master_key: [u8; N]) -> [u8; 16] {
+    BlockCipher::new(master_key).decrypt(ciphertext)
+}
+
+/// Decrypts one block with an already expanded key.
+pub(crate) fn decrypt_block(ciphertext: [u8; 16], round_keys: &[FieldElement]) -> [u8; 16] {
+    let mut state: [FieldElement; 16] = ciphertext.map(FieldElement);
+    let rounds = round_keys.len() / 16 - 1;
+
+    // Initial Round: AddRoundKey with the last round key
+    add_round_key(&mut state, &round_keys[rounds * 16..(rounds + 1) * 16]);
+
+    // Rounds Nr - 1 down to 1
+    for r in (1..rounds).rev() {
+        let key_start = r * 16;
+        let key_array: &[FieldElement; 16] = round_keys[key_start..key_start + 16]
+            .try_into()
+            .expect("Key slice should be 16 bytes");
+
+        aes_inv_round(&mut state, key_array, false);
+    }
+
+    // Final round uses the original key and skips InvMixColumns
+    let first_key: &[FieldElement; 16] = round_keys[0..16].try_into().expect("First key slice should be 16 bytes");
+    aes_inv_round(&mut state, first_key, true);
+
+    state.map(|b| b.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uses::aes::{
+        aes_encrypt, expand_key, inv_mix_columns, mix_columns, modes::test_util::for_each_backend,
+    };
+
+    // FIPS-197, Appendix C.1 (AES-128)
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    ];
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    ];
+    const CIPHERTEXT: [u8; 16] = [
+        0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30,
+        0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5, 0x5a,
+    ];
+
+    #[test]
+    fn test_fips197_c1_encrypt() {
+        for_each_backend(|| {
+            assert_eq!(aes_encrypt(PLAINTEXT, KEY), CIPHERTEXT);
+        });
+    }
+
+    #[test]
+    fn test_fips197_c1_decrypt() {
+        for_each_backend(|| {
+            assert_eq!(aes_decrypt(CIPHERTEXT, KEY), PLAINTEXT);
+        });
+    }
+
+    // FIPS-197, Appendix C.2 (AES-192)
+    const CIPHERTEXT_192: [u8; 16] = [
+        0xdd, 0xa9, 0x7c, 0xa4, 0x86, 0x4c, 0xdf, 0xe0,
+        0x6e, 0xaf, 0x70, 0xa0, 0xec, 0x0d, 0x71, 0x91,
+    ];
+    // FIPS-197, Appendix C.3 (AES-256)
+    const CIPHERTEXT_256: [u8; 16] = [
+        0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf,
+        0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49, 0x60, 0x89,
+    ];
+
+    #[test]
+    fn test_fips197_c2_aes192() {
+        for_each_backend(|| {
+            let key: [u8; 24] = core::array::from_fn(|i| i as u8);
+            assert_eq!(aes_encrypt(PLAINTEXT, key), CIPHERTEXT_192);
+            assert_eq!(aes_decrypt(CIPHERTEXT_192, key), PLAINTEXT);
+        });
+    }
+
+    #[test]
+    fn test_fips197_c3_aes256() {
+        for_each_backend(|| {
+            let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+            assert_eq!(aes_encrypt(PLAINTEXT, key), CIPHERTEXT_256);
+            assert_eq!(aes_decrypt(CIPHERTEXT_256, key), PLAINTEXT);
+        });
+    }
+
+    #[test]
+    fn test_expanded_key_lengths() {
+        assert_eq!(expand_key([0u8; 16]).len(), 176);
+        assert_eq!(expand_key([0u8; 24]).len(), 208);
+        assert_eq!(expand_key([0u8; 32]).len(), 240);
+    }
+
+    #[test]
+    fn test_aes256_last_round_key() {
+        // FIPS-197, Appendix A.3: w[56..60] for the AES-256 example key
+        let key: [u8; 32] = [
+            0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe,
+            0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77, 0x81,
+            0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7,
+            0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14, 0xdf, 0xf4,
+        ];
+        let w: Vec<u8> = expand_key(key)[224..240].iter().map(|b| b.0).collect();
+        assert_eq!(w, [
+            0xfe, 0x48, 0x90, 0xd1, 0xe6, 0x18, 0x8d, 0x0b,
+            0x04, 0x6d, 0xf3, 0x44, 0x70, 0x6c, 0x63, 0x1e,
+        ]);
+    }
+
+    #[test]
+    fn test_decrypt_inverts_encrypt() {
+        for_each_backend(|| {
+            let key = [0xA5u8; 16];
+            for seed in 0..32u8 {
+                let block = core::array::from_fn(|i| seed.wrapping_mul(31).wrapping_add(i as u8));
+                assert_eq!(aes_decrypt(aes_encrypt(block, key), key), block);
+            }
+        });
+    }
+
+    #[test]
+    fn test_inv_mix_columns_inverts_mix_columns() {
+        let original: [FieldElement; 16] = core::array::from_fn(|i| FieldElement(i as u8 * 17));
+        let mut state = original;
+        mix_columns(&mut state);
+        inv_mix_columns(&mut state);
+        assert_eq!(state, original);
+    }
+}
@@ -1,22 +1,27 @@
-use crate::{types::FieldElement, uses::aes::{aes_round, expand_key}};
+use crate::{types::FieldElement, uses::aes::{aes_round, modes::BlockCipher}};
 
-pub fn aes_encrypt(message: [u8; 16], master_key: [u8; 16]) -> [u8; 16] {
+/// Encrypts one block under a 16, 24 or 32 byte key (AES-128/192/256).
+///
+/// Uses AES-NI when the CPU supports it, otherwise the portable code (the
+/// constant-time [`BitslicedAes`](crate::uses::aes::BitslicedAes) with the
+/// `bitsliced` feature). The key schedule runs on every call; to encrypt
+/// many blocks under one key use [`Aes128`](crate::uses::aes::Aes128) and
+/// friends.
+pub fn aes_encrypt<const N: usize>(message: [u8; 16], master_key: [u8; N]) -> [u8; 16] {
+    BlockCipher::new(master_key).encrypt(message)
+}
+
+/// Encrypts one block with an already expanded key, so callers processing
+/// many blocks run the key schedule once.
+pub(crate) fn encrypt_block(message: [u8; 16], round_keys: &[FieldElement]) -> [u8; 16] {
     let mut state: [FieldElement; 16] = message.map(FieldElement);
-    let round_keys = expand_key(master_key);
+    let rounds = round_keys.len() / 16 - 1;
 
     // Initial Round: Just AddRoundKey
     add_round_key(&mut state, &round_keys[0..16]);
 
-    // // Rounds 1 through 9
-    // for r in 1..10 {
-    //     let key_start = r * 16;
-    //     aes_round(&mut state, &round_keys[key_start..key_start+16], false);
-    // }
-    // // Round 10: Final Round (last_round = true skips MixColumns)
-    // aes_round(&mut state, &round_keys[160..176], true);
-    
-    // In your loop (Rounds 1-9)
-    for r in 1..10 {
+    // Rounds 1 through Nr - 1
+    for r in 1..rounds {
         let key_start = r * 16;
         let key_slice = &round_keys[key_start..key_start + 16];
         
@@ -26,17 +31,18 @@ pub fn aes_encrypt(message: [u8; 16], master_key: [u8; 16]) -> [u8; 16] {
         aes_round(&mut state, key_array, false);
     }
 
-    // In your final round (Round 10)
-    let final_key: &[FieldElement; 16] = round_keys[160..176].try_into().expect("Final key slice should be 16 bytes");
+    // Final Round (last_round = true skips MixColumns)
+    let final_start = rounds * 16;
+    let final_key: &[FieldElement; 16] = round_keys[final_start..final_start + 16]
+        .try_into()
+        .expect("Final key slice should be 16 bytes");
     aes_round(&mut state, final_key, true);
     // Convert back to u8
-    let mut out = [0u8; 16];
-    for i in 0..16 { out[i] = state[i].0; }
-    out
+    state.map(|b| b.0)
 }
 
-fn add_round_key(state: &mut [FieldElement; 16], key: &[FieldElement]) {
+pub(crate) fn add_round_key(state: &mut [FieldElement; 16], key: &[FieldElement]) {
     for i in 0..16 {
         state[i] = state[i] + key[i];
     }
-}
\ No newline at end of file
+}
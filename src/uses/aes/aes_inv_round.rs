@@ -0,0 +1,30 @@
+use crate::{
+    types::FieldElement,
+    uses::aes::{inv_mix_columns, inv_s_box, inv_shift_rows}
+};
+
+/// One round of the inverse cipher (FIPS-197, section 5.3).
+///
+/// `last_round` is the final round of decryption, which has no InvMixColumns.
+pub fn aes_inv_round(
+    state: &mut [FieldElement; 16],
+    key: &[FieldElement; 16],
+    last_round: bool) {
+    // 1. InvShiftRows
+    inv_shift_rows(state);
+
+    // 2. InvSubBytes
+    for byte in state.iter_mut() {
+        *byte = inv_s_box(*byte);
+    }
+
+    // 3. AddRoundKey
+    for i in 0..16 {
+        state[i] = state[i] + key[i];
+    }
+
+    // 4. InvMixColumns (Skipped in the final round)
+    if !last_round {
+        inv_mix_columns(state);
+    }
+}
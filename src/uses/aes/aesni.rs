@@ -0,0 +1,163 @@
+//! AES-NI backend for x86_64, selected at runtime.
+//!
+//! Round keys come from the constant-time bitsliced key schedule and are
+//! stored as bytes, so they can be zeroized; each call loads them into XMM
+//! registers.
+
+use core::arch::x86_64::{
+    __m128i,
+    _mm_aesdec_si128,
+    _mm_aesdeclast_si128,
+    _mm_aesenc_si128,
+    _mm_aesenclast_si128,
+    _mm_aesimc_si128,
+    _mm_loadu_si128,
+    _mm_storeu_si128,
+    _mm_xor_si128,
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::uses::aes::{bitsliced::expand_key_ct, expand_key::MAX_ROUNDS};
+
+/// AES-128/192/256 using the AES-NI instructions.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AesNi {
+    /// Only the first `rounds + 1` entries of each schedule are used.
+    enc_keys: [[u8; 16]; MAX_ROUNDS + 1],
+    /// Round keys of the equivalent inverse cipher (FIPS-197, section 5.3.5).
+    dec_keys: [[u8; 16]; MAX_ROUNDS + 1],
+    #[zeroize(skip)]
+    rounds: usize,
+}
+
+impl AesNi {
+    /// Whether the running CPU supports AES-NI.
+    pub fn is_supported() -> bool {
+        std::arch::is_x86_feature_detected!("aes")
+    }
+
+    /// Returns `None` when the CPU lacks AES-NI.
+    pub fn new<const N: usize>(key: [u8; N]) -> Option<Self> {
+        if !Self::is_supported() {
+            return None;
+        }
+        let mut expanded = expand_key_ct(key);
+        let rounds = expanded.len() - 1;
+        let mut enc_keys = [[0u8; 16]; MAX_ROUNDS + 1];
+        let mut dec_keys = [[0u8; 16]; MAX_ROUNDS + 1];
+        enc_keys[..=rounds].copy_from_slice(&expanded);
+        expanded.zeroize();
+
+        for i in 0..=rounds {
+            let k = enc_keys[rounds - i];
+            dec_keys[i] = if i == 0 || i == rounds {
+                k
+            } else {
+                // SAFETY: AES-NI support was checked above
+                unsafe { inv_mix_columns(k) }
+            };
+        }
+        Some(AesNi { enc_keys, dec_keys, rounds })
+    }
+
+    pub fn encrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+        // SAFETY: an `AesNi` only exists if the CPU supports AES-NI
+        unsafe { encrypt(&self.enc_keys[..=self.rounds], block) }
+    }
+
+    pub fn decrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+        // SAFETY: as for `encrypt_block`
+        unsafe { decrypt(&self.dec_keys[..=self.rounds], block) }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn keys_are_zero(&self) -> bool {
+        self.enc_keys.iter().chain(&self.dec_keys).flatten().all(|&b| b == 0)
+    }
+}
+
+#[target_feature(enable = "aes")]
+unsafe fn encrypt(keys: &[[u8; 16]], block: [u8; 16]) -> [u8; 16] {
+    let rounds = keys.len() - 1;
+    let mut x = _mm_xor_si128(load(&block), load(&keys[0]));
+    for k in &keys[1..rounds] {
+        x = _mm_aesenc_si128(x, load(k));
+    }
+    store(_mm_aesenclast_si128(x, load(&keys[rounds])))
+}
+
+#[target_feature(enable = "aes")]
+unsafe fn decrypt(keys: &[[u8; 16]], block: [u8; 16]) -> [u8; 16] {
+    let rounds = keys.len() - 1;
+    let mut x = _mm_xor_si128(load(&block), load(&keys[0]));
+    for k in &keys[1..rounds] {
+        x = _mm_aesdec_si128(x, load(k));
+    }
+    store(_mm_aesdeclast_si128(x, load(&keys[rounds])))
+}
+
+#[target_feature(enable = "aes")]
+unsafe fn inv_mix_columns(key: [u8; 16]) -> [u8; 16] {
+    store(_mm_aesimc_si128(load(&key)))
+}
+
+#[inline(always)]
+fn load(bytes: &[u8; 16]) -> __m128i {
+    // SAFETY: `_mm_loadu_si128` has no alignment requirement and reads 16 bytes
+    unsafe { _mm_loadu_si128(bytes.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store(x: __m128i) -> [u8; 16] {
+    let mut out = [0u8; 16];
+    // SAFETY: `_mm_storeu_si128` has no alignment requirement and writes 16 bytes
+    unsafe { _mm_storeu_si128(out.as_mut_ptr().cast(), x) };
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uses::aes::{
+        aes_decrypt::decrypt_block,
+        aes_encrypt::encrypt_block,
+        expand_key,
+        BitslicedAes,
+    };
+    use rand::{Rng, RngCore};
+
+    fn cross_check<const N: usize>(rng: &mut impl RngCore) {
+        let mut key = [0u8; N];
+        rng.fill_bytes(&mut key);
+        let Some(ni) = AesNi::new(key) else { return };
+        let (reference, bitsliced) = (expand_key(key), BitslicedAes::new(key));
+
+        for _ in 0..32 {
+            let block: [u8; 16] = rng.r#gen();
+            let c = ni.encrypt_block(block);
+            assert_eq!(c, encrypt_block(block, &reference));
+            assert_eq!(c, bitsliced.encrypt_block(block));
+            assert_eq!(ni.decrypt_block(c), block);
+            assert_eq!(ni.decrypt_block(block), decrypt_block(block, &reference));
+        }
+    }
+
+    #[test]
+    fn test_matches_portable_on_random_inputs() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..8 {
+            cross_check::<16>(&mut rng);
+            cross_check::<24>(&mut rng);
+            cross_check::<32>(&mut rng);
+        }
+    }
+
+    #[test]
+    fn test_fips197_c1() {
+        let Some(ni) = AesNi::new::<16>(core::array::from_fn(|i| i as u8)) else { return };
+        let plaintext: [u8; 16] = core::array::from_fn(|i| (i * 0x11) as u8);
+        let c = ni.encrypt_block(plaintext);
+        assert_eq!(u128::from_be_bytes(c), 0x69c4_e0d8_6a7b_0430_d8cd_b780_70b4_c55a);
+        assert_eq!(ni.decrypt_block(c), plaintext);
+    }
+}
@@ -0,0 +1,487 @@
+//! Constant-time AES on bitsliced state.
+//!
+//! Eight blocks are processed together as eight `u128` bit planes: bit `b` of
+//! byte `p` of block `k` lives at position `16 * k + p` of plane `b`. SubBytes
+//! is the Boyar–Peralta boolean circuit and the linear layers are shifts and
+//! masks, so no step branches on or indexes by secret data. The key schedule
+//! uses the same S-box circuit.
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::uses::aes::{
+    expand_key::{num_rounds, MAX_ROUNDS},
+    get_rcon,
+};
+
+/// Number of blocks processed by one pass of the bitsliced rounds.
+pub const PARALLEL_BLOCKS: usize = 8;
+
+type State = [u128; 8];
+
+/// AES-128/192/256 with a bitsliced, constant-time implementation.
+///
+/// [`BitslicedAes::encrypt_blocks`] amortises each pass over eight blocks;
+/// single-block calls cost as much as eight.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct BitslicedAes {
+    /// Only the first `rounds + 1` entries are used.
+    round_keys: [State; MAX_ROUNDS + 1],
+    #[zeroize(skip)]
+    rounds: usize,
+}
+
+impl BitslicedAes {
+    pub fn new<const N: usize>(key: [u8; N]) -> Self {
+        let mut expanded = expand_key_ct(key);
+        let mut round_keys = [[0u128; 8]; MAX_ROUNDS + 1];
+        for (state, rk) in round_keys.iter_mut().zip(&expanded) {
+            *state = pack(&[*rk; PARALLEL_BLOCKS]);
+        }
+        expanded.zeroize();
+        BitslicedAes { round_keys, rounds: num_rounds(N) }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn keys_are_zero(&self) -> bool {
+        self.round_keys.iter().flatten().all(|&plane| plane == 0)
+    }
+
+    pub fn encrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+        let mut blocks = [block];
+        self.encrypt_blocks(&mut blocks);
+        blocks[0]
+    }
+
+    pub fn decrypt_block(&self, block: [u8; 16]) -> [u8; 16] {
+        let mut blocks = [block];
+        self.decrypt_blocks(&mut blocks);
+        blocks[0]
+    }
+
+    /// Encrypts `blocks` in place, eight at a time.
+    pub fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        self.process_blocks(blocks, Self::encrypt_state);
+    }
+
+    /// Decrypts `blocks` in place, eight at a time.
+    pub fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        self.process_blocks(blocks, Self::decrypt_state);
+    }
+
+    fn process_blocks(&self, blocks: &mut [[u8; 16]], f: fn(&Self, &mut State)) {
+        for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+            let mut batch = [[0u8; 16]; PARALLEL_BLOCKS];
+            batch[..chunk.len()].copy_from_slice(chunk);
+
+            let mut q = pack(&batch);
+            f(self, &mut q);
+            unpack(&q, &mut batch);
+            chunk.copy_from_slice(&batch[..chunk.len()]);
+
+            q.zeroize();
+            batch.zeroize();
+        }
+    }
+
+    fn encrypt_state(&self, q: &mut State) {
+        let rounds = self.rounds;
+        add_round_key(q, &self.round_keys[0]);
+        for rk in &self.round_keys[1..rounds] {
+            sub_bytes(q);
+            shift_rows(q);
+            mix_columns(q);
+            add_round_key(q, rk);
+        }
+        sub_bytes(q);
+        shift_rows(q);
+        add_round_key(q, &self.round_keys[rounds]);
+    }
+
+    fn decrypt_state(&self, q: &mut State) {
+        let rounds = self.rounds;
+        add_round_key(q, &self.round_keys[rounds]);
+        for rk in self.round_keys[1..rounds].iter().rev() {
+            inv_shift_rows(q);
+            inv_sub_bytes(q);
+            add_round_key(q, rk);
+            inv_mix_columns(q);
+        }
+        inv_shift_rows(q);
+        inv_sub_bytes(q);
+        add_round_key(q, &self.round_keys[0]);
+    }
+}
+
+/// The FIPS-197 key schedule with SubWord computed by the S-box circuit.
+pub(crate) fn expand_key_ct<const N: usize>(key: [u8; N]) -> Vec<[u8; 16]> {
+    const { assert!(N == 16 || N == 24 || N == 32, "AES keys are 16, 24 or 32 bytes") };
+
+    let nk = N / 4;
+    let total_words = 4 * (num_rounds(N) + 1);
+    // Sized up front so that pushing never reallocates and strands key bytes
+    let mut w: Vec<[u8; 4]> = Vec::with_capacity(total_words);
+    w.extend(key.chunks_exact(4).map(|c| [c[0], c[1], c[2], c[3]]));
+
+    for i in nk..total_words {
+        let mut temp = w[i - 1];
+        if i % nk == 0 {
+            temp.rotate_left(1);
+            temp = sub_word(temp);
+            temp[0] ^= get_rcon(i / nk).0;
+        } else if nk > 6 && i % nk == 4 {
+            temp = sub_word(temp);
+        }
+        let prev = w[i - nk];
+        w.push(core::array::from_fn(|j| prev[j] ^ temp[j]));
+        temp.zeroize();
+    }
+
+    let round_keys = w
+        .chunks_exact(4)
+        .map(|words| core::array::from_fn(|j| words[j / 4][j % 4]))
+        .collect();
+    w.zeroize();
+    round_keys
+}
+
+fn sub_word(word: [u8; 4]) -> [u8; 4] {
+    let mut block = [[0u8; 16]; PARALLEL_BLOCKS];
+    block[0][..4].copy_from_slice(&word);
+    let mut q = pack(&block);
+    sub_bytes(&mut q);
+    unpack(&q, &mut block);
+
+    let out = [block[0][0], block[0][1], block[0][2], block[0][3]];
+    q.zeroize();
+    block.zeroize();
+    out
+}
+
+/// Transposes eight blocks into bit planes.
+fn pack(blocks: &[[u8; 16]; PARALLEL_BLOCKS]) -> State {
+    let mut q = [0u128; 8];
+    for (k, block) in blocks.iter().enumerate() {
+        for (p, &byte) in block.iter().enumerate() {
+            for (b, plane) in q.iter_mut().enumerate() {
+                *plane |= (((byte >> b) & 1) as u128) << (16 * k + p);
+            }
+        }
+    }
+    q
+}
+
+fn unpack(q: &State, blocks: &mut [[u8; 16]; PARALLEL_BLOCKS]) {
+    for (k, block) in blocks.iter_mut().enumerate() {
+        for (p, byte) in block.iter_mut().enumerate() {
+            *byte = q
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (b, plane)| acc | ((((plane >> (16 * k + p)) & 1) as u8) << b));
+        }
+    }
+}
+
+/// Repeats a 16-bit mask over the eight block lanes.
+const fn lanes(mask: u16) -> u128 {
+    let mut out = 0u128;
+    let mut k = 0;
+    while k < PARALLEL_BLOCKS {
+        out |= (mask as u128) << (16 * k);
+        k += 1;
+    }
+    out
+}
+
+fn add_round_key(q: &mut State, rk: &State) {
+    for (plane, k) in q.iter_mut().zip(rk) {
+        *plane ^= k;
+    }
+}
+
+/// Boyar–Peralta S-box circuit (113 gates) on the bit planes, `q[7]` being
+/// the most significant bit.
+fn sub_bytes(q: &mut State) {
+    let (x0, x1, x2, x3) = (q[7], q[6], q[5], q[4]);
+    let (x4, x5, x6, x7) = (q[3], q[2], q[1], q[0]);
+
+    // Top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section: inversion in GF(2^8) via GF(2^4)
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation, including the affine constant 0x63
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+/// Inverse of the affine map of SubBytes, `y -> rotl(y,1) ^ rotl(y,3) ^ rotl(y,6) ^ 0x05`.
+fn inv_affine(q: &mut State) {
+    let a = *q;
+    for (i, plane) in q.iter_mut().enumerate() {
+        *plane = a[(i + 7) % 8] ^ a[(i + 5) % 8] ^ a[(i + 2) % 8];
+    }
+    q[0] = !q[0];
+    q[2] = !q[2];
+}
+
+/// `InvSubBytes(y) = A^-1(SubBytes(A^-1(y)))`, since `SubBytes = A ∘ inv`.
+fn inv_sub_bytes(q: &mut State) {
+    inv_affine(q);
+    sub_bytes(q);
+    inv_affine(q);
+}
+
+/// Row `r` of every column moves left by `r` columns.
+fn shift_rows(q: &mut State) {
+    for plane in q.iter_mut() {
+        let x = *plane;
+        *plane = (x & lanes(0x1111))
+            | ((x >> 4) & lanes(0x0222)) | ((x << 12) & lanes(0x2000))
+            | ((x >> 8) & lanes(0x0044)) | ((x << 8) & lanes(0x4400))
+            | ((x >> 12) & lanes(0x0008)) | ((x << 4) & lanes(0x8880));
+    }
+}
+
+fn inv_shift_rows(q: &mut State) {
+    for plane in q.iter_mut() {
+        let x = *plane;
+        *plane = (x & lanes(0x1111))
+            | ((x << 4) & lanes(0x2220)) | ((x >> 12) & lanes(0x0002))
+            | ((x >> 8) & lanes(0x0044)) | ((x << 8) & lanes(0x4400))
+            | ((x << 12) & lanes(0x8000)) | ((x >> 4) & lanes(0x0888));
+    }
+}
+
+/// Each byte takes the value of the next row down in its column.
+fn rotate_rows_1(x: u128) -> u128 {
+    ((x >> 1) & lanes(0x7777)) | ((x << 3) & lanes(0x8888))
+}
+
+fn rotate_rows_2(x: u128) -> u128 {
+    ((x >> 2) & lanes(0x3333)) | ((x << 2) & lanes(0xCCCC))
+}
+
+/// Multiplication by 2 in GF(2^8) on bit planes.
+fn xtime(t: State) -> State {
+    [t[7], t[0] ^ t[7], t[1], t[2] ^ t[7], t[3] ^ t[7], t[4], t[5], t[6]]
+}
+
+/// `b_r = 2(a_r ^ a_{r+1}) ^ a_{r+1} ^ a_{r+2} ^ a_{r+3}`
+fn mix_columns(q: &mut State) {
+    let r1 = q.map(rotate_rows_1);
+    let r2 = q.map(rotate_rows_2);
+    let doubled = xtime(core::array::from_fn(|i| q[i] ^ r1[i]));
+    for (i, plane) in q.iter_mut().enumerate() {
+        *plane = doubled[i] ^ r1[i] ^ r2[i] ^ rotate_rows_1(r2[i]);
+    }
+}
+
+/// InvMixColumns as a pre-multiplication `a_r ^= 4(a_r ^ a_{r+2})` followed
+/// by MixColumns.
+fn inv_mix_columns(q: &mut State) {
+    let u = xtime(xtime(core::array::from_fn(|i| q[i] ^ rotate_rows_2(q[i]))));
+    for (plane, u) in q.iter_mut().zip(u) {
+        *plane ^= u;
+    }
+    mix_columns(q);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::FieldElement,
+        uses::aes::{aes_decrypt::decrypt_block, aes_encrypt::encrypt_block, expand_key, inv_s_box, s_box},
+    };
+
+    #[test]
+    fn test_sub_bytes_matches_s_box() {
+        for chunk in (0..=255u8).collect::<Vec<_>>().chunks(128) {
+            let mut blocks = [[0u8; 16]; PARALLEL_BLOCKS];
+            for (i, &x) in chunk.iter().enumerate() {
+                blocks[i / 16][i % 16] = x;
+            }
+
+            let mut q = pack(&blocks);
+            sub_bytes(&mut q);
+            let mut forward = [[0u8; 16]; PARALLEL_BLOCKS];
+            unpack(&q, &mut forward);
+
+            inv_sub_bytes(&mut q);
+            let mut back = [[0u8; 16]; PARALLEL_BLOCKS];
+            unpack(&q, &mut back);
+
+            for (i, &x) in chunk.iter().enumerate() {
+                assert_eq!(forward[i / 16][i % 16], s_box(FieldElement(x)).0, "S({x:#04x})");
+                assert_eq!(inv_s_box(FieldElement(forward[i / 16][i % 16])).0, x);
+                assert_eq!(back[i / 16][i % 16], x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_schedule_matches_reference() {
+        let key: [u8; 32] = core::array::from_fn(|i| (i * 37) as u8);
+        let reference: Vec<u8> = expand_key(key).iter().map(|b| b.0).collect();
+        assert_eq!(expand_key_ct(key).concat(), reference);
+
+        let key: [u8; 24] = core::array::from_fn(|i| (i * 11 + 5) as u8);
+        let reference: Vec<u8> = expand_key(key).iter().map(|b| b.0).collect();
+        assert_eq!(expand_key_ct(key).concat(), reference);
+    }
+
+    #[test]
+    fn test_fips197_appendix_c() {
+        let plaintext: [u8; 16] = core::array::from_fn(|i| (i * 0x11) as u8);
+
+        let aes = BitslicedAes::new::<16>(core::array::from_fn(|i| i as u8));
+        let c = aes.encrypt_block(plaintext);
+        assert_eq!(u128::from_be_bytes(c), 0x69c4_e0d8_6a7b_0430_d8cd_b780_70b4_c55a);
+        assert_eq!(aes.decrypt_block(c), plaintext);
+
+        let aes = BitslicedAes::new::<24>(core::array::from_fn(|i| i as u8));
+        let c = aes.encrypt_block(plaintext);
+        assert_eq!(u128::from_be_bytes(c), 0xdda9_7ca4_864c_dfe0_6eaf_70a0_ec0d_7191);
+        assert_eq!(aes.decrypt_block(c), plaintext);
+
+        let aes = BitslicedAes::new::<32>(core::array::from_fn(|i| i as u8));
+        let c = aes.encrypt_block(plaintext);
+        assert_eq!(u128::from_be_bytes(c), 0x8ea2_b7ca_5167_45bf_eafc_4990_4b49_6089);
+        assert_eq!(aes.decrypt_block(c), plaintext);
+    }
+
+    #[test]
+    fn test_multi_block_matches_reference() {
+        let key = [0x5Au8; 16];
+        let round_keys = expand_key(key);
+        let aes = BitslicedAes::new(key);
+
+        // 19 blocks: two full passes and a partial one
+        let plaintext: Vec<[u8; 16]> = (0..19u8).map(|i| core::array::from_fn(|j| i.wrapping_mul(31) ^ j as u8)).collect();
+        let mut blocks = plaintext.clone();
+        aes.encrypt_blocks(&mut blocks);
+        for (c, p) in blocks.iter().zip(&plaintext) {
+            assert_eq!(*c, encrypt_block(*p, &round_keys));
+            assert_eq!(decrypt_block(*c, &round_keys), *p);
+        }
+
+        aes.decrypt_blocks(&mut blocks);
+        assert_eq!(blocks, plaintext);
+    }
+}
@@ -0,0 +1,243 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    uses::{
+        aes::modes::{ct_eq, xor_block, BlockCipher, Ctr},
+        universal_hash::BlockBuffer,
+    },
+    Error,
+    Result,
+};
+
+/// AES-CCM authenticated encryption (NIST SP 800-38C, RFC 3610).
+///
+/// The length field size `L` trades nonce length against message length:
+/// nonces are `15 - L` bytes and messages shorter than `2^(8L)` bytes.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AesCcm {
+    cipher: BlockCipher,
+    tag_len: usize,
+    length_size: usize,
+}
+
+impl AesCcm {
+    /// Panics unless `tag_len` is an even number in `4..=16` and
+    /// `length_size` is in `2..=8`.
+    pub fn new<const N: usize>(key: [u8; N], tag_len: usize, length_size: usize) -> Self {
+        assert!(
+            matches!(tag_len, 4 | 6 | 8 | 10 | 12 | 14 | 16),
+            "CCM tag length must be 4, 6, 8, 10, 12, 14 or 16 bytes"
+        );
+        assert!((2..=8).contains(&length_size), "CCM length field must be 2 to 8 bytes");
+        AesCcm { cipher: BlockCipher::new(key), tag_len, length_size }
+    }
+
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
+    /// Required nonce length, `15 - L` bytes.
+    pub fn nonce_len(&self) -> usize {
+        15 - self.length_size
+    }
+
+    /// Encrypts `buffer` in place and returns the authentication tag.
+    pub fn seal_detached(&self, nonce: &[u8], aad: &[u8], buffer: &mut [u8]) -> Result<Vec<u8>> {
+        self.check_lengths(nonce, buffer.len())?;
+
+        let mut tag = self.cbc_mac(nonce, aad, buffer);
+        let mut ctr = self.ctr(nonce);
+        ctr.apply_keystream(&mut tag);
+        ctr.apply_keystream(buffer);
+        Ok(tag[..self.tag_len].to_vec())
+    }
+
+    /// Decrypts `buffer` in place if `tag` verifies.
+    ///
+    /// On [`Error::AuthenticationFailed`] the buffer is restored to the
+    /// ciphertext, so no unauthenticated plaintext is released.
+    pub fn open_detached(&self, nonce: &[u8], aad: &[u8], buffer: &mut [u8], tag: &[u8]) -> Result<()> {
+        self.check_lengths(nonce, buffer.len())?;
+
+        let mut ctr = self.ctr(nonce);
+        let mut s0 = [0u8; 16];
+        ctr.apply_keystream(&mut s0);
+        ctr.apply_keystream(buffer);
+
+        let mut expected = self.cbc_mac(nonce, aad, buffer);
+        xor_block(&mut expected, &s0);
+        let valid = ct_eq(&expected[..self.tag_len], tag);
+        expected.zeroize();
+
+        if !valid {
+            let mut ctr = self.ctr(nonce);
+            ctr.apply_keystream(&mut s0);
+            ctr.apply_keystream(buffer);
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(())
+    }
+
+    /// Encrypts `plaintext`, returning `ciphertext || tag`.
+    pub fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut out = plaintext.to_vec();
+        let tag = self.seal_detached(nonce, aad, &mut out)?;
+        out.extend(tag);
+        Ok(out)
+    }
+
+    /// Verifies and decrypts `ciphertext || tag` produced by [`AesCcm::seal`].
+    pub fn open(&self, nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+        let Some(split) = sealed.len().checked_sub(self.tag_len) else {
+            return Err(Error::AuthenticationFailed);
+        };
+        let (ciphertext, tag) = sealed.split_at(split);
+
+        let mut out = ciphertext.to_vec();
+        self.open_detached(nonce, aad, &mut out, tag)?;
+        Ok(out)
+    }
+
+    fn check_lengths(&self, nonce: &[u8], text_len: usize) -> Result<()> {
+        if nonce.len() != self.nonce_len() {
+            return Err(Error::InvalidNonceLength);
+        }
+        if self.length_size < 8 && text_len as u64 >> (8 * self.length_size) != 0 {
+            return Err(Error::MessageTooLong);
+        }
+        Ok(())
+    }
+
+    /// CBC-MAC over `B0`, the length-prefixed associated data and the payload.
+    fn cbc_mac(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> [u8; 16] {
+        let mut b0 = [0u8; 16];
+        b0[0] = ((!aad.is_empty() as u8) << 6)
+            | (((self.tag_len - 2) / 2) as u8) << 3
+            | (self.length_size - 1) as u8;
+        b0[1..16 - self.length_size].copy_from_slice(nonce);
+        b0[16 - self.length_size..]
+            .copy_from_slice(&(plaintext.len() as u64).to_be_bytes()[8 - self.length_size..]);
+
+        let (cipher, mut state) = (&self.cipher, self.cipher.encrypt(b0));
+        let mut buffer = BlockBuffer::default();
+        let mut absorb = |block: [u8; 16]| {
+            xor_block(&mut state, &block);
+            state = cipher.encrypt(state);
+        };
+
+        if !aad.is_empty() {
+            buffer.update(&encode_aad_len(aad.len()), &mut absorb);
+            buffer.update(aad, &mut absorb);
+            buffer.pad(&mut absorb);
+        }
+        buffer.update(plaintext, &mut absorb);
+        buffer.pad(&mut absorb);
+        state
+    }
+
+    /// Counter blocks `A_i = flags || nonce || i`, starting from `A_0`.
+    fn ctr(&self, nonce: &[u8]) -> Ctr {
+        let mut a0 = [0u8; 16];
+        a0[0] = (self.length_size - 1) as u8;
+        a0[1..16 - self.length_size].copy_from_slice(nonce);
+        Ctr::from_cipher(self.cipher.clone(), a0, 8 * self.length_size as u32)
+    }
+}
+
+/// Length prefix of the associated data (SP 800-38C, appendix A.2.2).
+fn encode_aad_len(len: usize) -> Vec<u8> {
+    let len = len as u64;
+    if len < 0xFF00 {
+        (len as u16).to_be_bytes().to_vec()
+    } else if len <= u32::MAX as u64 {
+        [&[0xFF, 0xFE][..], &(len as u32).to_be_bytes()].concat()
+    } else {
+        [&[0xFF, 0xFF][..], &len.to_be_bytes()].concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::hex,
+        uses::aes::modes::test_util::{check_aead, for_each_backend},
+    };
+
+    const KEY: [u8; 16] = [
+        0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+        0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    ];
+
+    fn range(start: u8, len: u8) -> Vec<u8> {
+        (start..start + len).collect()
+    }
+
+    fn check(ccm: &AesCcm, nonce: &[u8], aad: &[u8], plaintext: &[u8], sealed: &str) {
+        check_aead(ccm, nonce, aad, plaintext, &hex(&[sealed]));
+    }
+
+    #[test]
+    fn test_sp800_38c_examples() {
+        for_each_backend(|| {
+            // Example 1: 7-byte nonce (L = 8), 4-byte tag
+            check(&AesCcm::new(KEY, 4, 8), &range(0x10, 7), &range(0, 8), &range(0x20, 4), "7162015b4dac255d");
+            // Example 2: 8-byte nonce (L = 7), 6-byte tag
+            check(
+                &AesCcm::new(KEY, 6, 7),
+                &range(0x10, 8),
+                &range(0, 16),
+                &range(0x20, 16),
+                "d2a1f0e051ea5f62081a7792073d593d1fc64fbfaccd",
+            );
+            // Example 3: 12-byte nonce (L = 3), 8-byte tag
+            check(
+                &AesCcm::new(KEY, 8, 3),
+                &range(0x10, 12),
+                &range(0, 20),
+                &range(0x20, 24),
+                "e3b201a9f5b71a7a9b1ceaeccd97e70b6176aad9a4428aa5484392fbc1b09951",
+            );
+        });
+    }
+
+    #[test]
+    fn test_rejects_tampering_and_restores_buffer() {
+        let ccm = AesCcm::new(KEY, 16, 2);
+        let nonce = range(0, 13);
+        let sealed = ccm.seal(&nonce, b"aad", b"attack at dawn").unwrap();
+
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 0x01;
+            assert_eq!(ccm.open(&nonce, b"aad", &tampered), Err(Error::AuthenticationFailed));
+        }
+
+        let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+        let mut buffer = ciphertext.to_vec();
+        assert!(ccm.open_detached(&nonce, b"", &mut buffer, tag).is_err());
+        assert_eq!(buffer, ciphertext);
+    }
+
+    #[test]
+    fn test_rejects_bad_lengths() {
+        let ccm = AesCcm::new(KEY, 8, 2);
+        assert_eq!(ccm.nonce_len(), 13);
+        assert_eq!(ccm.seal(&[0; 12], b"", b""), Err(Error::InvalidNonceLength));
+        assert_eq!(ccm.seal(&[0; 13], b"", &vec![0; 1 << 16]), Err(Error::MessageTooLong));
+        assert!(ccm.seal(&[0; 13], b"", &vec![0; (1 << 16) - 1]).is_ok());
+    }
+
+    #[test]
+    fn test_aad_length_encoding() {
+        assert_eq!(encode_aad_len(1), [0x00, 0x01]);
+        assert_eq!(encode_aad_len(0xFEFF), [0xFE, 0xFF]);
+        assert_eq!(encode_aad_len(0xFF00), [0xFF, 0xFE, 0x00, 0x00, 0xFF, 0x00]);
+    }
+
+    #[test]
+    #[should_panic(expected = "CCM tag length")]
+    fn test_odd_tag_length_should_panic() {
+        AesCcm::new(KEY, 5, 2);
+    }
+}
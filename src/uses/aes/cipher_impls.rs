@@ -0,0 +1,127 @@
+//! RustCrypto `cipher` trait implementations, enabled by the `cipher` feature,
+//! so that crates such as `ctr`, `cbc` and `aes-gcm` can run on our AES.
+//!
+//! The inherent `new` takes a `[u8; N]`; with `KeyInit` in scope, call
+//! `KeyInit::new(&key)` or `new_from_slice` to construct from a `Key`.
+
+use cipher::{
+    consts::{U16, U24, U32},
+    generic_array::GenericArray,
+    impl_simple_block_encdec,
+    AlgorithmName,
+    BlockCipher,
+    Key,
+    KeyInit,
+    KeySizeUser,
+};
+
+use crate::uses::aes::{Aes128, Aes192, Aes256};
+
+macro_rules! impl_cipher_traits {
+    ($name:ident, $key_size:ty) => {
+        impl KeySizeUser for $name {
+            type KeySize = $key_size;
+        }
+
+        impl KeyInit for $name {
+            fn new(key: &Key<Self>) -> Self {
+                $name::new((*key).into())
+            }
+        }
+
+        impl BlockCipher for $name {}
+
+        impl AlgorithmName for $name {
+            fn write_alg_name(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(stringify!($name))
+            }
+        }
+
+        impl_simple_block_encdec!(
+            $name, U16, cipher, block,
+            encrypt: {
+                let out = cipher.encrypt_block((*block.get_in()).into());
+                *block.get_out() = GenericArray::from(out);
+            }
+            decrypt: {
+                let out = cipher.decrypt_block((*block.get_in()).into());
+                *block.get_out() = GenericArray::from(out);
+            }
+        );
+    };
+}
+
+impl_cipher_traits!(Aes128, U16);
+impl_cipher_traits!(Aes192, U24);
+impl_cipher_traits!(Aes256, U32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::modes::{
+        cbc_encrypt,
+        sp800_38a::{plaintext, IV, KEY_128, KEY_256},
+        test_util::for_each_backend,
+        Ctr,
+    };
+    use cipher::{BlockDecrypt, BlockEncrypt, BlockEncryptMut, KeyIvInit, StreamCipher};
+
+    fn encrypt_generic<C>(key: &[u8], block: [u8; 16]) -> [u8; 16]
+    where
+        C: BlockEncrypt + BlockDecrypt + KeyInit + cipher::BlockSizeUser<BlockSize = U16>,
+    {
+        let cipher = C::new_from_slice(key).unwrap();
+        let mut b = GenericArray::from(block);
+        cipher.encrypt_block(&mut b);
+        let c = b.into();
+        cipher.decrypt_block(&mut b);
+        assert_eq!(b.as_slice(), block);
+        c
+    }
+
+    #[test]
+    fn test_generic_block_cipher_fips197() {
+        let p = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeffu128.to_be_bytes();
+        let key: Vec<u8> = (0..32).collect();
+        for_each_backend(|| {
+            assert_eq!(encrypt_generic::<Aes128>(&key[..16], p), hex(&["69c4e0d86a7b0430d8cdb78070b4c55a"])[..]);
+            assert_eq!(encrypt_generic::<Aes192>(&key[..24], p), hex(&["dda97ca4864cdfe06eaf70a0ec0d7191"])[..]);
+            assert_eq!(encrypt_generic::<Aes256>(&key, p), hex(&["8ea2b7ca516745bfeafc49904b496089"])[..]);
+        });
+        assert!(Aes128::new_from_slice(&key).is_err());
+    }
+
+    #[test]
+    fn test_ctr_crate_matches_modes_ctr() {
+        let counter: [u8; 16] = hex(&["f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"]).try_into().unwrap();
+        let mut expected = plaintext();
+        Ctr::new(KEY_256, counter).apply_keystream(&mut expected);
+
+        for_each_backend(|| {
+            let mut data = plaintext();
+            let mut ctr = ctr::Ctr128BE::<Aes256>::new(&KEY_256.into(), &counter.into());
+            ctr.apply_keystream(&mut data);
+            assert_eq!(data, expected);
+        });
+    }
+
+    #[test]
+    fn test_cbc_crate_matches_modes_cbc() {
+        let ours = cbc_encrypt(KEY_128, IV, &plaintext());
+        for_each_backend(|| {
+            let theirs = cbc::Encryptor::<Aes128>::new(&KEY_128.into(), &IV.into())
+                .encrypt_padded_vec_mut::<cipher::block_padding::Pkcs7>(&plaintext());
+            assert_eq!(theirs, ours);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "bitsliced")]
+    fn test_bitsliced_feature_keeps_cipher_traits_off_reference_aes() {
+        use crate::uses::aes::modes::Backend;
+
+        let backend = <Aes128 as KeyInit>::new(&KEY_128.into()).cipher.backend();
+        assert_ne!(backend, Backend::Reference);
+    }
+}
@@ -0,0 +1,163 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    galois_fields::gf2_128::{gf2_128_mul_x, GCM_REDUCTION},
+    uses::{
+        aes::modes::{ct_eq, xor_block, BlockCipher},
+        universal_hash::BlockBuffer,
+    },
+    Error,
+    Result,
+};
+
+/// Streaming AES-CMAC (NIST SP 800-38B, RFC 4493).
+///
+/// The last complete block is held back until [`Cmac::finalize`], since only
+/// the final block is masked with a subkey.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Cmac {
+    cipher: BlockCipher,
+    k1: [u8; 16],
+    k2: [u8; 16],
+    state: [u8; 16],
+    buffer: BlockBuffer,
+    held: Option<[u8; 16]>,
+}
+
+impl Cmac {
+    pub fn new<const N: usize>(key: [u8; N]) -> Self {
+        let cipher = BlockCipher::new(key);
+        let (k1, k2) = subkeys(&cipher);
+        Cmac { cipher, k1, k2, state: [0; 16], buffer: BlockBuffer::default(), held: None }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let (cipher, state, held) = (&self.cipher, &mut self.state, &mut self.held);
+        self.buffer.update(data, |block| {
+            if let Some(previous) = held.replace(block) {
+                xor_block(state, &previous);
+                *state = cipher.encrypt(*state);
+            }
+        });
+    }
+
+    /// Returns the full 16-byte tag; truncate it for shorter MACs.
+    pub fn finalize(mut self) -> [u8; 16] {
+        let pending = self.buffer.pending();
+        let last = match self.held {
+            Some(block) if pending.is_empty() => {
+                let mut last = block;
+                xor_block(&mut last, &self.k1);
+                last
+            }
+            _ => {
+                // Incomplete (or empty) final block: pad with 10..0 and use K2
+                let mut last = [0u8; 16];
+                last[..pending.len()].copy_from_slice(pending);
+                last[pending.len()] = 0x80;
+                xor_block(&mut last, &self.k2);
+                if let Some(block) = self.held {
+                    xor_block(&mut self.state, &block);
+                    self.state = self.cipher.encrypt(self.state);
+                }
+                last
+            }
+        };
+
+        xor_block(&mut self.state, &last);
+        self.cipher.encrypt(self.state)
+    }
+
+    /// Checks `tag`, which may be truncated to its first 1-16 bytes, in
+    /// constant time.
+    pub fn verify(self, tag: &[u8]) -> Result<()> {
+        let expected = self.finalize();
+        if tag.is_empty() || tag.len() > 16 || !ct_eq(&expected[..tag.len()], tag) {
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(())
+    }
+}
+
+/// One-shot CMAC of `message`.
+pub fn cmac<const N: usize>(key: [u8; N], message: &[u8]) -> [u8; 16] {
+    let mut mac = Cmac::new(key);
+    mac.update(message);
+    mac.finalize()
+}
+
+/// `K1 = dbl(E(K, 0))`, `K2 = dbl(K1)` (SP 800-38B, section 6.1).
+fn subkeys(cipher: &BlockCipher) -> ([u8; 16], [u8; 16]) {
+    let l = u128::from_be_bytes(cipher.encrypt([0; 16]));
+    let k1 = gf2_128_mul_x::<GCM_REDUCTION>(l);
+    let k2 = gf2_128_mul_x::<GCM_REDUCTION>(k1);
+    (k1.to_be_bytes(), k2.to_be_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::modes::{
+        sp800_38a::{plaintext, KEY_128, KEY_256},
+        test_util::for_each_backend,
+    };
+
+    #[test]
+    fn test_rfc4493_subkeys() {
+        for_each_backend(|| {
+            let (k1, k2) = subkeys(&BlockCipher::new(KEY_128));
+            assert_eq!(k1.to_vec(), hex(&["fbeed618357133667c85e08f7236a8de"]));
+            assert_eq!(k2.to_vec(), hex(&["f7ddac306ae266ccf90bc11ee46d513b"]));
+        });
+    }
+
+    #[test]
+    fn test_sp800_38b_aes128() {
+        for_each_backend(|| {
+            let m = plaintext();
+            assert_eq!(cmac(KEY_128, &[]).to_vec(), hex(&["bb1d6929e95937287fa37d129b756746"]));
+            assert_eq!(cmac(KEY_128, &m[..16]).to_vec(), hex(&["070a16b46b4d4144f79bdd9dd04a287c"]));
+            assert_eq!(cmac(KEY_128, &m[..40]).to_vec(), hex(&["dfa66747de9ae63030ca32611497c827"]));
+            assert_eq!(cmac(KEY_128, &m).to_vec(), hex(&["51f0bebf7e3b9d92fc49741779363cfe"]));
+        });
+    }
+
+    #[test]
+    fn test_sp800_38b_aes256() {
+        for_each_backend(|| {
+            let m = plaintext();
+            assert_eq!(cmac(KEY_256, &[]).to_vec(), hex(&["028962f61b7bf89efc6b551f4667d983"]));
+            assert_eq!(cmac(KEY_256, &m).to_vec(), hex(&["e1992190549f6ed5696a2c056c315410"]));
+        });
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let m = plaintext();
+        for split in [1, 7, 16, 17, 32] {
+            let mut mac = Cmac::new(KEY_128);
+            for chunk in m[..48].chunks(split) {
+                mac.update(chunk);
+            }
+            assert_eq!(mac.finalize(), cmac(KEY_128, &m[..48]));
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let tag = cmac(KEY_128, b"message");
+        let verify = |t: &[u8]| {
+            let mut mac = Cmac::new(KEY_128);
+            mac.update(b"message");
+            mac.verify(t)
+        };
+        assert_eq!(verify(&tag), Ok(()));
+        assert_eq!(verify(&tag[..8]), Ok(()));
+        assert_eq!(verify(&[]), Err(Error::AuthenticationFailed));
+
+        let mut bad = tag;
+        bad[0] ^= 1;
+        assert_eq!(verify(&bad), Err(Error::AuthenticationFailed));
+    }
+}
@@ -1,15 +1,31 @@
 use crate::{types::FieldElement, uses::aes::{get_rcon, s_box}};
-pub fn expand_key(master_key: [u8; 16]) -> Vec<FieldElement> {
-    // AES-128 needs 11 round keys (16 bytes each = 176 bytes / 44 words)
-    let mut w: Vec<FieldElement> = master_key.iter().map(|&b| FieldElement(b)).collect();
+
+/// Number of rounds for a key of `key_len` bytes: 10, 12 or 14.
+pub const fn num_rounds(key_len: usize) -> usize {
+    key_len / 4 + 6
+}
+
+/// Rounds of AES-256, the most of any key size.
+pub(crate) const MAX_ROUNDS: usize = num_rounds(32);
+
+/// Expands a 16, 24 or 32 byte key into `num_rounds + 1` round keys.
+pub fn expand_key<const N: usize>(master_key: [u8; N]) -> Vec<FieldElement> {
+    const { assert!(N == 16 || N == 24 || N == 32, "AES keys are 16, 24 or 32 bytes") };
+
+    // Nk words in the key; AES-128 needs 44 words, AES-192 52, AES-256 60
+    let nk = N / 4;
+    let total_words = 4 * (num_rounds(N) + 1);
+    // Sized up front so that pushing never reallocates and strands key bytes
+    let mut w: Vec<FieldElement> = Vec::with_capacity(4 * total_words);
+    w.extend(master_key.iter().map(|&b| FieldElement(b)));
     
-    for i in 4..44 {
+    for i in nk..total_words {
         // 'temp' is the previous 4-byte word
         let mut temp = [
             w[(i-1)*4], w[(i-1)*4 + 1], w[(i-1)*4 + 2], w[(i-1)*4 + 3]
         ];
 
-        if i % 4 == 0 {
+        if i % nk == 0 {
             // 1. RotWord: [a, b, c, d] -> [b, c, d, a]
             temp.rotate_left(1);
 
@@ -19,14 +35,19 @@ pub fn expand_key(master_key: [u8; 16]) -> Vec<FieldElement> {
             }
 
             // 3. XOR with Rcon: Only the first byte of the word
-            temp[0] = temp[0] + get_rcon(i / 4);
+            temp[0] = temp[0] + get_rcon(i / nk);
+        } else if nk > 6 && i % nk == 4 {
+            // AES-256 only: an extra SubWord halfway through each key block
+            for byte in temp.iter_mut() {
+                *byte = s_box(*byte);
+            }
         }
 
-        // w[i] = w[i-4] ^ temp
+        // w[i] = w[i-Nk] ^ temp
         for j in 0..4 {
-            let val = w[(i-4)*4 + j] + temp[j];
+            let val = w[(i-nk)*4 + j] + temp[j];
             w.push(val);
         }
     }
     w
-}
\ No newline at end of file
+}
@@ -0,0 +1,302 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    uses::{
+        aes::modes::{ct_eq, xor_block, BlockCipher, Ctr},
+        universal_hash::Ghash,
+    },
+    Error,
+    Result,
+};
+
+/// Longest plaintext GCM can protect: 2^39 - 256 bits.
+const MAX_PLAINTEXT_LEN: u64 = (1 << 36) - 32;
+/// Longest associated data or IV: 2^64 - 1 bits, rounded down to whole bytes.
+const MAX_AAD_LEN: u64 = (1 << 61) - 1;
+
+/// AES-GCM authenticated encryption (NIST SP 800-38D).
+///
+/// Any non-empty IV is accepted; 96-bit IVs are used directly as the counter
+/// prefix, longer or shorter ones are compressed with GHASH first. An IV must
+/// never repeat under the same key.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AesGcm {
+    cipher: BlockCipher,
+    h: [u8; 16],
+    tag_len: usize,
+}
+
+impl AesGcm {
+    /// AES-GCM with a full 16-byte tag.
+    pub fn new<const N: usize>(key: [u8; N]) -> Self {
+        Self::with_tag_len(key, 16)
+    }
+
+    /// AES-GCM with a truncated tag.
+    ///
+    /// Panics unless `tag_len` is one of the lengths SP 800-38D permits:
+    /// 16, 15, 14, 13, 12, 8 or 4 bytes. Short tags need the usage limits of
+    /// SP 800-38D Appendix C.
+    pub fn with_tag_len<const N: usize>(key: [u8; N], tag_len: usize) -> Self {
+        assert!(
+            matches!(tag_len, 12..=16 | 8 | 4),
+            "GCM tag length must be 16, 15, 14, 13, 12, 8 or 4 bytes"
+        );
+        let cipher = BlockCipher::new(key);
+        let h = cipher.encrypt([0; 16]);
+        AesGcm { cipher, h, tag_len }
+    }
+
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
+    /// Encrypts `buffer` in place and returns the authentication tag.
+    pub fn seal_detached(&self, iv: &[u8], aad: &[u8], buffer: &mut [u8]) -> Result<Vec<u8>> {
+        check_lengths(iv.len(), aad.len(), buffer.len())?;
+        let j0 = self.j0(iv);
+
+        self.ctr(j0).apply_keystream(buffer);
+        Ok(self.tag(j0, aad, buffer)[..self.tag_len].to_vec())
+    }
+
+    /// Verifies `tag` and only then decrypts `buffer` in place.
+    ///
+    /// On [`Error::AuthenticationFailed`] the buffer is left untouched.
+    pub fn open_detached(&self, iv: &[u8], aad: &[u8], buffer: &mut [u8], tag: &[u8]) -> Result<()> {
+        check_lengths(iv.len(), aad.len(), buffer.len())?;
+        let j0 = self.j0(iv);
+
+        let mut expected = self.tag(j0, aad, buffer);
+        let valid = ct_eq(&expected[..self.tag_len], tag);
+        expected.zeroize();
+        if !valid {
+            return Err(Error::AuthenticationFailed);
+        }
+
+        self.ctr(j0).apply_keystream(buffer);
+        Ok(())
+    }
+
+    /// Encrypts `plaintext`, returning `ciphertext || tag`.
+    pub fn seal(&self, iv: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut out = plaintext.to_vec();
+        let tag = self.seal_detached(iv, aad, &mut out)?;
+        out.extend(tag);
+        Ok(out)
+    }
+
+    /// Verifies and decrypts `ciphertext || tag` produced by [`AesGcm::seal`].
+    pub fn open(&self, iv: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+        let Some(split) = sealed.len().checked_sub(self.tag_len) else {
+            return Err(Error::AuthenticationFailed);
+        };
+        let (ciphertext, tag) = sealed.split_at(split);
+
+        let mut out = ciphertext.to_vec();
+        self.open_detached(iv, aad, &mut out, tag)?;
+        Ok(out)
+    }
+
+    /// The pre-counter block `J0` (SP 800-38D, section 7.1, step 2).
+    fn j0(&self, iv: &[u8]) -> [u8; 16] {
+        if iv.len() == 12 {
+            let mut j0 = [0u8; 16];
+            j0[..12].copy_from_slice(iv);
+            j0[15] = 1;
+            return j0;
+        }
+
+        let mut ghash = Ghash::new(self.h);
+        ghash.update_padded(iv);
+        ghash.update(&(iv.len() as u128 * 8).to_be_bytes());
+        ghash.finalize()
+    }
+
+    /// Keystream for the payload, starting at `inc32(J0)`.
+    fn ctr(&self, j0: [u8; 16]) -> Ctr {
+        let mut counter = j0;
+        let low = u32::from_be_bytes([j0[12], j0[13], j0[14], j0[15]]).wrapping_add(1);
+        counter[12..].copy_from_slice(&low.to_be_bytes());
+        Ctr::from_cipher(self.cipher.clone(), counter, 32)
+    }
+
+    /// Full-length tag `E(K, J0) ^ GHASH(A || C || len(A) || len(C))`.
+    fn tag(&self, j0: [u8; 16], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+        let mut ghash = Ghash::new(self.h);
+        ghash.update_padded(aad);
+        ghash.update_padded(ciphertext);
+        ghash.update(&(aad.len() as u64 * 8).to_be_bytes());
+        ghash.update(&(ciphertext.len() as u64 * 8).to_be_bytes());
+
+        let mut tag = ghash.finalize();
+        xor_block(&mut tag, &self.cipher.encrypt(j0));
+        tag
+    }
+}
+
+fn check_lengths(iv_len: usize, aad_len: usize, text_len: usize) -> Result<()> {
+    if iv_len == 0 || iv_len as u64 > MAX_AAD_LEN {
+        return Err(Error::InvalidNonceLength);
+    }
+    if text_len as u64 > MAX_PLAINTEXT_LEN || aad_len as u64 > MAX_AAD_LEN {
+        return Err(Error::MessageTooLong);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{array, hex},
+        uses::aes::modes::test_util::{check_aead, for_each_backend},
+    };
+
+    // Test cases 1-5 and 15 from the GCM specification (McGrew & Viega)
+    const KEY: &str = "feffe9928665731c6d6a8f9467308308";
+    const IV: &str = "cafebabefacedbaddecaf888";
+    const PLAINTEXT: &str = "d9313225f88406e5a55909c5aff5269a\
+                             86a7a9531534f7da2e4c303d8a318a72\
+                             1c3c0c95956809532fcf0e2449a6b525\
+                             b16aedf5aa0de657ba637b391aafd255";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+
+    fn check(gcm: &AesGcm, iv: &str, aad: &str, plaintext: &[u8], ciphertext: &str, tag: &str) {
+        check_aead(gcm, &hex(&[iv]), &hex(&[aad]), plaintext, &hex(&[ciphertext, tag]));
+    }
+
+    #[test]
+    fn test_case_1_and_2_zero_key() {
+        for_each_backend(|| {
+            let gcm = AesGcm::new([0u8; 16]);
+            check(&gcm, "000000000000000000000000", "", &[], "", "58e2fccefa7e3061367f1d57a4e7455a");
+            check(
+                &gcm,
+                "000000000000000000000000",
+                "",
+                &[0; 16],
+                "0388dace60b6a392f328c2b971b2fe78",
+                "ab6e47d42cec13bdf53a67b21257bddf",
+            );
+        });
+    }
+
+    #[test]
+    fn test_case_3_and_4_with_aad() {
+        for_each_backend(|| {
+            let gcm = AesGcm::new(array::<16>(KEY));
+            let ciphertext = "42831ec2217774244b7221b784d0d49c\
+                              e3aa212f2c02a4e035c17e2329aca12e\
+                              21d514b25466931c7d8f6a5aac84aa05\
+                              1ba30b396a0aac973d58e091473f5985";
+            check(&gcm, IV, "", &hex(&[PLAINTEXT]), ciphertext, "4d5c2af327cd64a62cf35abd2ba6fab4");
+            check(
+                &gcm,
+                IV,
+                AAD,
+                &hex(&[PLAINTEXT])[..60],
+                &ciphertext[..120],
+                "5bc94fbc3221a5db94fae95ae7121a47",
+            );
+        });
+    }
+
+    #[test]
+    fn test_case_5_and_long_iv() {
+        for_each_backend(|| {
+            let gcm = AesGcm::new(array::<16>(KEY));
+            let plaintext = &hex(&[PLAINTEXT])[..60];
+            check(
+                &gcm,
+                "cafebabefacedbad",
+                AAD,
+                plaintext,
+                "61353b4c2806934a777ff51fa22a4755\
+                 699b2a714fcdc6f83766e5f97b6c7423\
+                 73806900e49f24b22b097544d4896b42\
+                 4989b5e1ebac0f07c23f4598",
+                "3612d2e79e3b0785561be14aaca2fccb",
+            );
+            // 60-byte IV with the test case 3 plaintext prefix, cross-checked against OpenSSL
+            check(
+                &gcm,
+                "9313225df88406e5a55909c5aff5269a\
+                 86a7a9531534f7da2e4c303d8a318a72\
+                 1c3c0c95956809532fcf0e2449a6b525\
+                 b16aedf5aa0de657ba637b39",
+                AAD,
+                plaintext,
+                "2b4b26fb49f400296428f090cdb8671a\
+                 60f2f674c7d2635c67c52763caccfb7a\
+                 fbde37c47ceaeaf102e38224d71d8e8c\
+                 6a6ed055a28dcef35ee92cd9",
+                "9a58d4b7c0030413d4cc72a5b67c11df",
+            );
+        });
+    }
+
+    #[test]
+    fn test_case_15_aes256() {
+        for_each_backend(|| {
+            let gcm = AesGcm::new(array::<32>(&[KEY, KEY].concat()));
+            check(
+                &gcm,
+                IV,
+                "",
+                &hex(&[PLAINTEXT]),
+                "522dc1f099567d07f47f37a32a84427d\
+                 643a8cdcbfe5c0c97598a2bd2555d1aa\
+                 8cb08e48590dbb3da7b08b1056828838\
+                 c5f61e6393ba7a0abcc9f662898015ad",
+                "b094dac5d93471bdec1a502270e3cc6c",
+            );
+        });
+    }
+
+    #[test]
+    fn test_truncated_tag_is_prefix() {
+        let (iv, aad, plaintext) = (hex(&[IV]), hex(&[AAD]), hex(&[PLAINTEXT]));
+        let full = AesGcm::new(array::<16>(KEY)).seal(&iv, &aad, &plaintext).unwrap();
+
+        let gcm = AesGcm::with_tag_len(array::<16>(KEY), 12);
+        let sealed = gcm.seal(&iv, &aad, &plaintext).unwrap();
+        assert_eq!(sealed[..], full[..full.len() - 4]);
+        assert_eq!(gcm.open(&iv, &aad, &sealed).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_rejects_tampering() {
+        let gcm = AesGcm::new(array::<16>(KEY));
+        let (iv, aad) = (hex(&[IV]), hex(&[AAD]));
+        let sealed = gcm.seal(&iv, &aad, b"attack at dawn").unwrap();
+
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 0x01;
+            assert_eq!(gcm.open(&iv, &aad, &tampered), Err(Error::AuthenticationFailed));
+        }
+        assert_eq!(gcm.open(&iv, b"other aad", &sealed), Err(Error::AuthenticationFailed));
+        assert_eq!(gcm.open(&iv, &aad, &sealed[..15]), Err(Error::AuthenticationFailed));
+
+        // A failed open leaves the caller's buffer as it was
+        let mut buffer = sealed[..14].to_vec();
+        let bad_tag = [0u8; 16];
+        assert!(gcm.open_detached(&iv, &aad, &mut buffer, &bad_tag).is_err());
+        assert_eq!(buffer, sealed[..14]);
+    }
+
+    #[test]
+    fn test_rejects_bad_lengths() {
+        let gcm = AesGcm::new([0u8; 16]);
+        assert_eq!(gcm.seal(&[], &[], b"x"), Err(Error::InvalidNonceLength));
+        assert_eq!(check_lengths(12, 0, MAX_PLAINTEXT_LEN as usize), Ok(()));
+        assert_eq!(check_lengths(12, 0, MAX_PLAINTEXT_LEN as usize + 1), Err(Error::MessageTooLong));
+    }
+
+    #[test]
+    #[should_panic(expected = "GCM tag length")]
+    fn test_invalid_tag_length_should_panic() {
+        AesGcm::with_tag_len([0u8; 16], 10);
+    }
+}
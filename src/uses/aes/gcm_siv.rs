@@ -0,0 +1,242 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    uses::{
+        aes::modes::{ct_eq, BlockCipher},
+        universal_hash::Polyval,
+    },
+    Error,
+    Result,
+};
+
+/// Longest plaintext or associated data: 2^36 bytes (RFC 8452, section 6).
+const MAX_LEN: u64 = 1 << 36;
+
+/// AES-GCM-SIV nonce-misuse-resistant AEAD (RFC 8452) for 16 or 32 byte keys.
+///
+/// Repeating a nonce only reveals whether the same message was sealed twice
+/// under it, instead of breaking confidentiality and authenticity as in GCM.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AesGcmSiv {
+    key_generating_key: BlockCipher,
+    key_len: usize,
+}
+
+/// Per-nonce keys derived from the key-generating key.
+#[derive(Zeroize, ZeroizeOnDrop)]
+struct DerivedKeys {
+    auth_key: [u8; 16],
+    enc_cipher: BlockCipher,
+}
+
+impl AesGcmSiv {
+    pub fn new<const N: usize>(key: [u8; N]) -> Self {
+        const { assert!(N == 16 || N == 32, "AES-GCM-SIV keys are 16 or 32 bytes") };
+        AesGcmSiv { key_generating_key: BlockCipher::new(key), key_len: N }
+    }
+
+    /// Encrypts `buffer` in place and returns the 16-byte tag.
+    pub fn seal_detached(&self, nonce: &[u8], aad: &[u8], buffer: &mut [u8]) -> Result<Vec<u8>> {
+        let nonce = check_lengths(nonce, aad.len(), buffer.len())?;
+        let keys = self.derive_keys(nonce);
+
+        let tag = keys.tag(nonce, aad, buffer);
+        keys.apply_keystream(tag, buffer);
+        Ok(tag.to_vec())
+    }
+
+    /// Decrypts `buffer` in place if `tag` verifies.
+    ///
+    /// On [`Error::AuthenticationFailed`] the buffer is restored to the
+    /// ciphertext, so no unauthenticated plaintext is released.
+    pub fn open_detached(&self, nonce: &[u8], aad: &[u8], buffer: &mut [u8], tag: &[u8]) -> Result<()> {
+        let nonce = check_lengths(nonce, aad.len(), buffer.len())?;
+        let Ok(tag) = <[u8; 16]>::try_from(tag) else {
+            return Err(Error::AuthenticationFailed);
+        };
+        let keys = self.derive_keys(nonce);
+
+        keys.apply_keystream(tag, buffer);
+        let mut expected = keys.tag(nonce, aad, buffer);
+        let valid = ct_eq(&expected, &tag);
+        expected.zeroize();
+
+        if !valid {
+            keys.apply_keystream(tag, buffer);
+            return Err(Error::AuthenticationFailed);
+        }
+        Ok(())
+    }
+
+    /// Encrypts `plaintext`, returning `ciphertext || tag`.
+    pub fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut out = plaintext.to_vec();
+        let tag = self.seal_detached(nonce, aad, &mut out)?;
+        out.extend(tag);
+        Ok(out)
+    }
+
+    /// Verifies and decrypts `ciphertext || tag` produced by [`AesGcmSiv::seal`].
+    pub fn open(&self, nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+        let Some(split) = sealed.len().checked_sub(16) else {
+            return Err(Error::AuthenticationFailed);
+        };
+        let (ciphertext, tag) = sealed.split_at(split);
+
+        let mut out = ciphertext.to_vec();
+        self.open_detached(nonce, aad, &mut out, tag)?;
+        Ok(out)
+    }
+
+    /// Record-encryption and authentication keys for `nonce` (RFC 8452, section 4).
+    fn derive_keys(&self, nonce: &[u8; 12]) -> DerivedKeys {
+        // Each AES call contributes its first 8 bytes: 2 blocks for the
+        // authentication key, then 2 or 4 for the encryption key
+        let mut material = [0u8; 48];
+        for (i, chunk) in material[..16 + self.key_len].chunks_exact_mut(8).enumerate() {
+            let mut block = [0u8; 16];
+            block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+            chunk.copy_from_slice(&self.key_generating_key.encrypt(block)[..8]);
+        }
+
+        let mut auth_key = [0u8; 16];
+        auth_key.copy_from_slice(&material[..16]);
+        let enc_cipher = match self.key_len {
+            16 => BlockCipher::new::<16>(material[16..32].try_into().expect("16-byte key")),
+            _ => BlockCipher::new::<32>(material[16..48].try_into().expect("32-byte key")),
+        };
+        material.zeroize();
+
+        DerivedKeys { auth_key, enc_cipher }
+    }
+}
+
+impl DerivedKeys {
+    /// `E(enc_key, POLYVAL(A, P) ^ nonce)` with the top bit of the block cleared.
+    fn tag(&self, nonce: &[u8; 12], aad: &[u8], plaintext: &[u8]) -> [u8; 16] {
+        let mut polyval = Polyval::new(self.auth_key);
+        polyval.update_padded(aad);
+        polyval.update_padded(plaintext);
+        polyval.update(&(aad.len() as u64 * 8).to_le_bytes());
+        polyval.update(&(plaintext.len() as u64 * 8).to_le_bytes());
+
+        let mut s = polyval.finalize();
+        for (a, b) in s.iter_mut().zip(nonce) {
+            *a ^= b;
+        }
+        s[15] &= 0x7f;
+        self.enc_cipher.encrypt(s)
+    }
+
+    /// CTR keyed by the tag, with a little-endian 32-bit counter in the
+    /// first four bytes that wraps without carrying.
+    fn apply_keystream(&self, tag: [u8; 16], data: &mut [u8]) {
+        let mut counter_block = tag;
+        counter_block[15] |= 0x80;
+        let mut counter = u32::from_le_bytes([tag[0], tag[1], tag[2], tag[3]]);
+
+        for chunk in data.chunks_mut(16) {
+            counter_block[..4].copy_from_slice(&counter.to_le_bytes());
+            let keystream = self.enc_cipher.encrypt(counter_block);
+            for (byte, k) in chunk.iter_mut().zip(keystream) {
+                *byte ^= k;
+            }
+            counter = counter.wrapping_add(1);
+        }
+    }
+}
+
+fn check_lengths(nonce: &[u8], aad_len: usize, text_len: usize) -> Result<&[u8; 12]> {
+    let nonce = nonce.try_into().map_err(|_| Error::InvalidNonceLength)?;
+    if text_len as u64 > MAX_LEN || aad_len as u64 > MAX_LEN {
+        return Err(Error::MessageTooLong);
+    }
+    Ok(nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::hex,
+        uses::aes::modes::test_util::{check_aead, for_each_backend},
+    };
+
+    const NONCE: &str = "030000000000000000000000";
+
+    fn check(siv: &AesGcmSiv, aad: &str, plaintext: &str, sealed: &str) {
+        check_aead(siv, &hex(&[NONCE]), &hex(&[aad]), &hex(&[plaintext]), &hex(&[sealed]));
+    }
+
+    #[test]
+    fn test_rfc8452_c1_aes128() {
+        for_each_backend(|| {
+            let siv = AesGcmSiv::new::<16>(hex(&["01000000000000000000000000000000"]).try_into().unwrap());
+            check(&siv, "", "", "dc20e2d83f25705bb49e439eca56de25");
+            check(&siv, "", "0100000000000000", "b5d839330ac7b786578782fff6013b815b287c22493a364c");
+            check(
+                &siv,
+                "01",
+                "02000000000000000000000000000000\
+                 03000000000000000000000000000000",
+                "620048ef3c1e73e57e02bb8562c416a3\
+                 19e73e4caac8e96a1ecb2933145a1d71\
+                 e6af6a7f87287da059a71684ed3498e1",
+            );
+        });
+    }
+
+    #[test]
+    fn test_rfc8452_c2_aes256() {
+        for_each_backend(|| {
+            let key = hex(&["01000000000000000000000000000000", "00000000000000000000000000000000"]);
+            let siv = AesGcmSiv::new::<32>(key.try_into().unwrap());
+            check(&siv, "", "", "07f5f4169bbf55a8400cd47ea6fd400f");
+            check(&siv, "", "0100000000000000", "c2ef328e5c71c83b843122130f7364b761e0b97427e3df28");
+            check(
+                &siv,
+                "01",
+                "02000000000000000000000000000000\
+                 03000000000000000000000000000000",
+                "07dad364bfc2b9da89116d7bef6daaaf\
+                 6f255510aa654f920ac81b94e8bad365\
+                 aea1bad12702e1965604374aab96dbbc",
+            );
+        });
+    }
+
+    #[test]
+    fn test_nonce_reuse_is_deterministic() {
+        let siv = AesGcmSiv::new([7u8; 16]);
+        let nonce = [0u8; 12];
+        let a = siv.seal(&nonce, b"", b"message one").unwrap();
+        assert_eq!(a, siv.seal(&nonce, b"", b"message one").unwrap());
+        assert_ne!(a, siv.seal(&nonce, b"", b"message two").unwrap());
+    }
+
+    #[test]
+    fn test_rejects_tampering_and_restores_buffer() {
+        let siv = AesGcmSiv::new([7u8; 32]);
+        let nonce = [1u8; 12];
+        let sealed = siv.seal(&nonce, b"aad", b"attack at dawn").unwrap();
+
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 0x80;
+            assert_eq!(siv.open(&nonce, b"aad", &tampered), Err(Error::AuthenticationFailed));
+        }
+
+        let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+        let mut buffer = ciphertext.to_vec();
+        assert!(siv.open_detached(&nonce, b"other", &mut buffer, tag).is_err());
+        assert_eq!(buffer, ciphertext);
+    }
+
+    #[test]
+    fn test_rejects_bad_nonce_length() {
+        let siv = AesGcmSiv::new([0u8; 16]);
+        assert_eq!(siv.seal(&[0; 16], b"", b""), Err(Error::InvalidNonceLength));
+        assert_eq!(check_lengths(&[0; 12], 0, MAX_LEN as usize + 1), Err(Error::MessageTooLong));
+    }
+}
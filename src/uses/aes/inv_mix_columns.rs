@@ -0,0 +1,21 @@
+use crate::types::FieldElement;
+
+pub fn inv_mix_columns(state: &mut [FieldElement; 16]) {
+    let e = FieldElement(0x0E);
+    let b = FieldElement(0x0B);
+    let d = FieldElement(0x0D);
+    let n = FieldElement(0x09);
+
+    for i in 0..4 {
+        let c = i * 4;
+        let s0 = state[c];
+        let s1 = state[c + 1];
+        let s2 = state[c + 2];
+        let s3 = state[c + 3];
+
+        state[c]     = (e * s0) + (b * s1) + (d * s2) + (n * s3);
+        state[c + 1] = (n * s0) + (e * s1) + (b * s2) + (d * s3);
+        state[c + 2] = (d * s0) + (n * s1) + (e * s2) + (b * s3);
+        state[c + 3] = (b * s0) + (d * s1) + (n * s2) + (e * s3);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::types::FieldElement;
+
+pub(crate)fn inv_shift_rows(state: &mut [FieldElement; 16]) {
+    let temp = *state;
+    // Row 1 (indices 1, 5, 9, 13): rotate right by 1
+    state[1]  = temp[13];
+    state[5]  = temp[1];
+    state[9]  = temp[5];
+    state[13] = temp[9];
+
+    // Row 2 (indices 2, 6, 10, 14): rotate right by 2
+    state[2]  = temp[10];
+    state[6]  = temp[14];
+    state[10] = temp[2];
+    state[14] = temp[6];
+
+    // Row 3 (indices 3, 7, 11, 15): rotate right by 3
+    state[3]  = temp[7];
+    state[7]  = temp[11];
+    state[11] = temp[15];
+    state[15] = temp[3];
+}
@@ -0,0 +1,243 @@
+use zeroize::Zeroize;
+
+use crate::{
+    uses::aes::modes::{ct_eq, BlockCipher},
+    Error,
+    Result,
+};
+
+/// Default initial value of RFC 3394, section 2.2.3.1.
+const DEFAULT_IV: [u8; 8] = [0xA6; 8];
+/// High half of the alternative initial value of RFC 5649, section 3.
+const PADDED_IV_PREFIX: [u8; 4] = [0xA6, 0x59, 0x59, 0xA6];
+
+/// Wraps `key_data` under `kek` (RFC 3394).
+///
+/// `key_data` must be a multiple of 8 bytes and at least 16 bytes long; use
+/// [`aes_key_wrap_with_padding`] for other lengths.
+pub fn aes_key_wrap<const N: usize>(kek: [u8; N], key_data: &[u8]) -> Result<Vec<u8>> {
+    if key_data.len() < 16 || !key_data.len().is_multiple_of(8) {
+        return Err(Error::InvalidKeyDataLength);
+    }
+    Ok(wrap(&BlockCipher::new(kek), DEFAULT_IV, key_data))
+}
+
+/// Unwraps the output of [`aes_key_wrap`], failing with
+/// [`Error::AuthenticationFailed`] if the integrity check does not match.
+pub fn aes_key_unwrap<const N: usize>(kek: [u8; N], wrapped: &[u8]) -> Result<Vec<u8>> {
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+        return Err(Error::InvalidCiphertextLength);
+    }
+    let (iv, mut key_data) = unwrap(&BlockCipher::new(kek), wrapped);
+    if !ct_eq(&iv, &DEFAULT_IV) {
+        key_data.zeroize();
+        return Err(Error::AuthenticationFailed);
+    }
+    Ok(key_data)
+}
+
+/// Wraps key data of any length from 1 to 2^32 - 1 bytes (RFC 5649).
+pub fn aes_key_wrap_with_padding<const N: usize>(kek: [u8; N], key_data: &[u8]) -> Result<Vec<u8>> {
+    let Ok(mli) = u32::try_from(key_data.len()) else {
+        return Err(Error::InvalidKeyDataLength);
+    };
+    if mli == 0 {
+        return Err(Error::InvalidKeyDataLength);
+    }
+
+    let mut iv = [0u8; 8];
+    iv[..4].copy_from_slice(&PADDED_IV_PREFIX);
+    iv[4..].copy_from_slice(&mli.to_be_bytes());
+
+    let mut padded = key_data.to_vec();
+    padded.resize(key_data.len().next_multiple_of(8), 0);
+
+    let cipher = BlockCipher::new(kek);
+    let wrapped = if padded.len() == 8 {
+        // A single semiblock is encrypted directly as `AIV || P`
+        let mut block = [0u8; 16];
+        block[..8].copy_from_slice(&iv);
+        block[8..].copy_from_slice(&padded);
+        let out = cipher.encrypt(block).to_vec();
+        block.zeroize();
+        out
+    } else {
+        wrap(&cipher, iv, &padded)
+    };
+    padded.zeroize();
+    Ok(wrapped)
+}
+
+/// Unwraps the output of [`aes_key_wrap_with_padding`], checking the
+/// alternative initial value, the message length and the zero padding.
+pub fn aes_key_unwrap_with_padding<const N: usize>(kek: [u8; N], wrapped: &[u8]) -> Result<Vec<u8>> {
+    if wrapped.len() < 16 || !wrapped.len().is_multiple_of(8) {
+        return Err(Error::InvalidCiphertextLength);
+    }
+
+    let cipher = BlockCipher::new(kek);
+    let (iv, mut padded) = if wrapped.len() == 16 {
+        let mut block = cipher.decrypt(wrapped.try_into().expect("wrapped should be 16 bytes"));
+        let result = (block[..8].try_into().expect("8-byte half"), block[8..].to_vec());
+        block.zeroize();
+        result
+    } else {
+        unwrap(&cipher, wrapped)
+    };
+
+    // Checked together so a failure does not reveal which part was wrong
+    let mli = u32::from_be_bytes([iv[4], iv[5], iv[6], iv[7]]) as usize;
+    let length_ok = mli <= padded.len() && mli + 8 > padded.len();
+    let padding_ok = length_ok && padded[mli..].iter().fold(0u8, |acc, &b| acc | b) == 0;
+    if !(ct_eq(&iv[..4], &PADDED_IV_PREFIX) && padding_ok) {
+        padded.zeroize();
+        return Err(Error::AuthenticationFailed);
+    }
+
+    padded.truncate(mli);
+    Ok(padded)
+}
+
+/// The wrapping process W of RFC 3394, section 2.2.1 (index-based form).
+fn wrap(cipher: &BlockCipher, iv: [u8; 8], key_data: &[u8]) -> Vec<u8> {
+    let mut a = iv;
+    let mut r = key_data.to_vec();
+    let n = r.len() / 8;
+    let mut block = [0u8; 16];
+
+    for j in 0..6 {
+        for (i, semiblock) in r.chunks_exact_mut(8).enumerate() {
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(semiblock);
+            block = cipher.encrypt(block);
+
+            let t = (n * j + i + 1) as u64;
+            a.copy_from_slice(&block[..8]);
+            for (x, y) in a.iter_mut().zip(t.to_be_bytes()) {
+                *x ^= y;
+            }
+            semiblock.copy_from_slice(&block[8..]);
+        }
+    }
+    block.zeroize();
+
+    let mut out = a.to_vec();
+    out.extend_from_slice(&r);
+    r.zeroize();
+    out
+}
+
+/// The unwrapping process W^-1, returning the recovered initial value and key data.
+fn unwrap(cipher: &BlockCipher, wrapped: &[u8]) -> ([u8; 8], Vec<u8>) {
+    let mut a: [u8; 8] = wrapped[..8].try_into().expect("8-byte semiblock");
+    let mut r = wrapped[8..].to_vec();
+    let n = r.len() / 8;
+    let mut block = [0u8; 16];
+
+    for j in (0..6).rev() {
+        for (i, semiblock) in r.chunks_exact_mut(8).enumerate().rev() {
+            let t = (n * j + i + 1) as u64;
+            for (x, y) in a.iter_mut().zip(t.to_be_bytes()) {
+                *x ^= y;
+            }
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(semiblock);
+            block = cipher.decrypt(block);
+
+            a.copy_from_slice(&block[..8]);
+            semiblock.copy_from_slice(&block[8..]);
+        }
+    }
+    block.zeroize();
+    (a, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util::hex, uses::aes::modes::test_util::for_each_backend};
+
+    const KEY_DATA: &str = "00112233445566778899aabbccddeeff";
+
+    #[test]
+    fn test_rfc3394_4_1_128_bit_kek() {
+        for_each_backend(|| {
+            let kek: [u8; 16] = core::array::from_fn(|i| i as u8);
+            let wrapped = hex(&["1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5"]);
+            assert_eq!(aes_key_wrap(kek, &hex(&[KEY_DATA])), Ok(wrapped.clone()));
+            assert_eq!(aes_key_unwrap(kek, &wrapped), Ok(hex(&[KEY_DATA])));
+        });
+    }
+
+    #[test]
+    fn test_rfc3394_4_6_256_bit_key_and_kek() {
+        for_each_backend(|| {
+            let kek: [u8; 32] = core::array::from_fn(|i| i as u8);
+            let key_data = hex(&[KEY_DATA, "000102030405060708090a0b0c0d0e0f"]);
+            let wrapped = hex(&[
+                "28c9f404c4b810f4cbccb35cfb87f826",
+                "3f5786e2d80ed326cbc7f0e71a99f43b",
+                "fb988b9b7a02dd21",
+            ]);
+            assert_eq!(aes_key_wrap(kek, &key_data), Ok(wrapped.clone()));
+            assert_eq!(aes_key_unwrap(kek, &wrapped), Ok(key_data));
+        });
+    }
+
+    #[test]
+    fn test_rfc5649_section_6() {
+        for_each_backend(|| {
+            let kek: [u8; 24] = hex(&["5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8"]).try_into().unwrap();
+
+            let key_data = hex(&["c37b7e6492584340bed12207808941155068f738"]);
+            let wrapped = hex(&["138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a"]);
+            assert_eq!(aes_key_wrap_with_padding(kek, &key_data), Ok(wrapped.clone()));
+            assert_eq!(aes_key_unwrap_with_padding(kek, &wrapped), Ok(key_data));
+
+            let key_data = hex(&["466f7250617369"]);
+            let wrapped = hex(&["afbeb0f07dfbf5419200f2ccb50bb24f"]);
+            assert_eq!(aes_key_wrap_with_padding(kek, &key_data), Ok(wrapped.clone()));
+            assert_eq!(aes_key_unwrap_with_padding(kek, &wrapped), Ok(key_data));
+        });
+    }
+
+    #[test]
+    fn test_padded_roundtrip_all_short_lengths() {
+        let kek = [0x42u8; 16];
+        for len in 1..=40 {
+            let key_data: Vec<u8> = (0..len as u8).collect();
+            let wrapped = aes_key_wrap_with_padding(kek, &key_data).unwrap();
+            assert_eq!(wrapped.len(), key_data.len().next_multiple_of(8) + 8);
+            assert_eq!(aes_key_unwrap_with_padding(kek, &wrapped), Ok(key_data));
+        }
+    }
+
+    #[test]
+    fn test_integrity_check_failures() {
+        let kek = [0x42u8; 16];
+        let mut wrapped = aes_key_wrap(kek, &hex(&[KEY_DATA])).unwrap();
+        wrapped[10] ^= 1;
+        assert_eq!(aes_key_unwrap(kek, &wrapped), Err(Error::AuthenticationFailed));
+        assert_eq!(aes_key_unwrap([0x43u8; 16], &wrapped), Err(Error::AuthenticationFailed));
+
+        // Wrapped without padding is not a valid padded wrap, and vice versa
+        let plain = aes_key_wrap(kek, &hex(&[KEY_DATA])).unwrap();
+        assert_eq!(aes_key_unwrap_with_padding(kek, &plain), Err(Error::AuthenticationFailed));
+        let padded = aes_key_wrap_with_padding(kek, &hex(&[KEY_DATA])).unwrap();
+        assert_eq!(aes_key_unwrap(kek, &padded), Err(Error::AuthenticationFailed));
+
+        let mut short = aes_key_wrap_with_padding(kek, b"abc").unwrap();
+        short[0] ^= 1;
+        assert_eq!(aes_key_unwrap_with_padding(kek, &short), Err(Error::AuthenticationFailed));
+    }
+
+    #[test]
+    fn test_rejects_bad_lengths() {
+        let kek = [0u8; 16];
+        assert_eq!(aes_key_wrap(kek, &[0; 8]), Err(Error::InvalidKeyDataLength));
+        assert_eq!(aes_key_wrap(kek, &[0; 20]), Err(Error::InvalidKeyDataLength));
+        assert_eq!(aes_key_wrap_with_padding(kek, &[]), Err(Error::InvalidKeyDataLength));
+        assert_eq!(aes_key_unwrap(kek, &[0; 16]), Err(Error::InvalidCiphertextLength));
+        assert_eq!(aes_key_unwrap_with_padding(kek, &[0; 20]), Err(Error::InvalidCiphertextLength));
+    }
+}
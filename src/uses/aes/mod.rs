@@ -1,17 +1,50 @@
 pub mod aes_encrypt;
-pub use aes_encrypt::{aes_encrypt};
+pub use aes_encrypt::aes_encrypt;
+pub mod aes_decrypt;
+pub use aes_decrypt::aes_decrypt;
+pub mod aes_cipher;
+pub use aes_cipher::{Aes128, Aes192, Aes256};
+#[cfg(feature = "cipher")]
+mod cipher_impls;
+#[cfg(target_arch = "x86_64")]
+pub mod aesni;
+#[cfg(target_arch = "x86_64")]
+pub use aesni::AesNi;
+pub mod bitsliced;
+pub use bitsliced::BitslicedAes;
 pub mod aes_round;
 pub use aes_round::aes_round;
+pub mod aes_inv_round;
+pub use aes_inv_round::aes_inv_round;
 pub mod expand_key;
 pub use expand_key::expand_key;
 pub mod get_rcon;
-pub(crate)use get_rcon::get_rcon;
+pub(crate) use get_rcon::get_rcon;
 pub mod mix_columns;
 pub use mix_columns::mix_columns;
+pub mod inv_mix_columns;
+pub use inv_mix_columns::inv_mix_columns;
 pub mod s_box;
-pub use s_box::{s_box, inv_s_box};
+pub use s_box::{inv_s_box, s_box};
 
 pub mod shift_rows;
-pub(crate)use shift_rows::shift_rows;
-pub(crate)mod xtime;
-pub(crate)use xtime::xtime;
\ No newline at end of file
+pub(crate) use shift_rows::shift_rows;
+pub mod inv_shift_rows;
+pub(crate) use inv_shift_rows::inv_shift_rows;
+pub(crate) mod xtime;
+pub(crate) use xtime::xtime;
+pub mod modes;
+pub mod gcm;
+pub use gcm::AesGcm;
+pub mod gcm_siv;
+pub use gcm_siv::AesGcmSiv;
+pub mod cmac;
+pub use cmac::{Cmac, cmac};
+pub mod ccm;
+pub use ccm::AesCcm;
+pub mod key_wrap;
+pub use key_wrap::{
+    aes_key_unwrap, aes_key_unwrap_with_padding, aes_key_wrap, aes_key_wrap_with_padding,
+};
+pub mod xts;
+pub use xts::AesXts;
@@ -0,0 +1,244 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    uses::{aes::modes::{xor_block, BlockCipher}, universal_hash::BlockBuffer},
+    Error,
+    Result,
+};
+
+/// Streaming CBC encryption (NIST SP 800-38A, section 6.2) with PKCS#7
+/// padding applied by [`CbcEncryptor::finalize`].
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct CbcEncryptor {
+    cipher: BlockCipher,
+    chain: [u8; 16],
+    buffer: BlockBuffer,
+}
+
+impl CbcEncryptor {
+    pub fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        CbcEncryptor { cipher: BlockCipher::new(key), chain: iv, buffer: BlockBuffer::default() }
+    }
+
+    /// Encrypts `data`, returning the ciphertext of every block completed so far.
+    pub fn update(&mut self, data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(data.len() + 16);
+        let (cipher, chain) = (&self.cipher, &mut self.chain);
+        self.buffer.update(data, |mut block| {
+            xor_block(&mut block, chain);
+            *chain = cipher.encrypt(block);
+            out.extend_from_slice(chain);
+        });
+        out
+    }
+
+    /// Pads the remaining input and returns the final ciphertext block.
+    pub fn finalize(mut self) -> Vec<u8> {
+        let pending = self.buffer.pending();
+        let mut block = [(16 - pending.len()) as u8; 16];
+        block[..pending.len()].copy_from_slice(pending);
+
+        xor_block(&mut block, &self.chain);
+        self.chain = self.cipher.encrypt(block);
+        block.zeroize();
+        self.chain.to_vec()
+    }
+}
+
+/// Streaming CBC decryption that strips PKCS#7 padding.
+///
+/// The last complete block is held back until [`CbcDecryptor::finalize`],
+/// since only then is it known to carry the padding. CBC is unauthenticated:
+/// plaintext returned by [`CbcDecryptor::update`] may have been tampered with.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct CbcDecryptor {
+    cipher: BlockCipher,
+    chain: [u8; 16],
+    buffer: BlockBuffer,
+    held: Option<[u8; 16]>,
+}
+
+impl CbcDecryptor {
+    pub fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        CbcDecryptor {
+            cipher: BlockCipher::new(key),
+            chain: iv,
+            buffer: BlockBuffer::default(),
+            held: None,
+        }
+    }
+
+    /// Decrypts `data`, returning the plaintext of every block except the last.
+    pub fn update(&mut self, data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(data.len() + 16);
+        let (cipher, chain, held) = (&self.cipher, &mut self.chain, &mut self.held);
+        self.buffer.update(data, |block| {
+            if let Some(previous) = held.replace(block) {
+                out.extend_from_slice(&decrypt_chained(cipher, chain, previous));
+            }
+        });
+        out
+    }
+
+    /// Decrypts the final block and removes its padding.
+    ///
+    /// Fails if the ciphertext was not a non-empty multiple of 16 bytes or
+    /// the padding is malformed; both indicate a corrupted ciphertext.
+    pub fn finalize(mut self) -> Result<Vec<u8>> {
+        let Some(last) = self.held.take() else {
+            return Err(Error::InvalidCiphertextLength);
+        };
+        if !self.buffer.pending().is_empty() {
+            return Err(Error::InvalidCiphertextLength);
+        }
+
+        let mut block = decrypt_chained(&self.cipher, &mut self.chain, last);
+        let result = match unpad(&block) {
+            Some(len) => Ok(block[..len].to_vec()),
+            None => Err(Error::InvalidPadding),
+        };
+        block.zeroize();
+        result
+    }
+}
+
+fn decrypt_chained(cipher: &BlockCipher, chain: &mut [u8; 16], block: [u8; 16]) -> [u8; 16] {
+    let mut plaintext = cipher.decrypt(block);
+    xor_block(&mut plaintext, chain);
+    *chain = block;
+    plaintext
+}
+
+/// Length of the message in a PKCS#7 padded block, checked without branching
+/// on the padding bytes.
+fn unpad(block: &[u8; 16]) -> Option<usize> {
+    let pad = block[15];
+    let mut bad = (pad == 0) as u8 | (pad > 16) as u8;
+    for (i, &b) in block.iter().enumerate() {
+        let in_padding = (i + pad as usize >= 16) as u8;
+        bad |= in_padding & (b != pad) as u8;
+    }
+    (bad == 0).then(|| 16 - pad as usize)
+}
+
+/// One-shot CBC encryption with PKCS#7 padding.
+pub fn cbc_encrypt<const N: usize>(key: [u8; N], iv: [u8; 16], plaintext: &[u8]) -> Vec<u8> {
+    let mut encryptor = CbcEncryptor::new(key, iv);
+    let mut out = encryptor.update(plaintext);
+    out.extend(encryptor.finalize());
+    out
+}
+
+/// One-shot CBC decryption, rejecting malformed length or padding.
+pub fn cbc_decrypt<const N: usize>(key: [u8; N], iv: [u8; 16], ciphertext: &[u8]) -> Result<Vec<u8>> {
+    let mut decryptor = CbcDecryptor::new(key, iv);
+    let mut out = decryptor.update(ciphertext);
+    match decryptor.finalize() {
+        Ok(last) => {
+            out.extend(last);
+            Ok(out)
+        }
+        Err(e) => {
+            out.zeroize();
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::modes::{
+        sp800_38a::{plaintext, IV, KEY_128, KEY_256},
+        test_util::for_each_backend,
+    };
+
+    fn sp800_38a_f21() -> Vec<u8> {
+        hex(&[
+            "7649abac8119b246cee98e9b12e9197d",
+            "5086cb9b507219ee95db113a917678b2",
+            "73bed6b8e3c1743b7116e69e22229516",
+            "3ff1caa1681fac09120eca307586e1a7",
+        ])
+    }
+
+    #[test]
+    fn test_sp800_38a_f21_cbc_aes128() {
+        for_each_backend(|| {
+            let ciphertext = cbc_encrypt(KEY_128, IV, &plaintext());
+            // The vectors are block-aligned, so PKCS#7 appends one full block
+            assert_eq!(ciphertext.len(), 80);
+            assert_eq!(ciphertext[..64], sp800_38a_f21());
+            assert_eq!(cbc_decrypt(KEY_128, IV, &ciphertext), Ok(plaintext()));
+        });
+    }
+
+    #[test]
+    fn test_sp800_38a_f25_cbc_aes256() {
+        for_each_backend(|| {
+            let expected = hex(&[
+                "f58c4c04d6e5f1ba779eabfb5f7bfbd6",
+                "9cfc4e967edb808d679f777bc6702c7d",
+                "39f23369a9d9bacfa530e26304231461",
+                "b2eb05e2c39be9fcda6c19078c6a9d1b",
+            ]);
+            let ciphertext = cbc_encrypt(KEY_256, IV, &plaintext());
+            assert_eq!(ciphertext[..64], expected);
+            assert_eq!(cbc_decrypt(KEY_256, IV, &ciphertext), Ok(plaintext()));
+        });
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..77u8).collect();
+
+        let mut encryptor = CbcEncryptor::new(KEY_128, IV);
+        let mut ciphertext = Vec::new();
+        for chunk in data.chunks(7) {
+            ciphertext.extend(encryptor.update(chunk));
+        }
+        ciphertext.extend(encryptor.finalize());
+        assert_eq!(ciphertext, cbc_encrypt(KEY_128, IV, &data));
+
+        let mut decryptor = CbcDecryptor::new(KEY_128, IV);
+        let mut recovered = Vec::new();
+        for chunk in ciphertext.chunks(11) {
+            recovered.extend(decryptor.update(chunk));
+        }
+        recovered.extend(decryptor.finalize().unwrap());
+        assert_eq!(recovered, data);
+    }
+
+    #[test]
+    fn test_padding_lengths() {
+        for len in 0..=32 {
+            let data = vec![0xA5; len];
+            let ciphertext = cbc_encrypt(KEY_128, IV, &data);
+            assert_eq!(ciphertext.len(), (len / 16 + 1) * 16);
+            assert_eq!(cbc_decrypt(KEY_128, IV, &ciphertext), Ok(data));
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed_ciphertext() {
+        assert_eq!(cbc_decrypt(KEY_128, IV, &[]), Err(Error::InvalidCiphertextLength));
+        assert_eq!(cbc_decrypt(KEY_128, IV, &[0; 17]), Err(Error::InvalidCiphertextLength));
+
+        // The raw SP 800-38A ciphertext carries no padding block
+        assert_eq!(cbc_decrypt(KEY_128, IV, &sp800_38a_f21()), Err(Error::InvalidPadding));
+    }
+
+    #[test]
+    fn test_unpad() {
+        let mut block = [0u8; 16];
+        block[15] = 1;
+        assert_eq!(unpad(&block), Some(15));
+        assert_eq!(unpad(&[16; 16]), Some(0));
+        assert_eq!(unpad(&[17; 16]), None);
+        assert_eq!(unpad(&[0; 16]), None);
+        block[14] = 3;
+        block[15] = 2;
+        assert_eq!(unpad(&block), None);
+    }
+}
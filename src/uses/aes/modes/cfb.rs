@@ -0,0 +1,111 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::uses::aes::modes::BlockCipher;
+
+/// Shift register and keystream shared by the CFB encryptor and decryptor.
+///
+/// Ciphertext bytes are written into `register` as they are produced, so
+/// once a block is complete it is the input for the next keystream block.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+struct Cfb {
+    cipher: BlockCipher,
+    register: [u8; 16],
+    keystream: [u8; 16],
+    pos: usize,
+}
+
+impl Cfb {
+    fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        Cfb { cipher: BlockCipher::new(key), register: iv, keystream: [0; 16], pos: 16 }
+    }
+
+    fn process(&mut self, data: &mut [u8], decrypt: bool) {
+        for byte in data.iter_mut() {
+            if self.pos == 16 {
+                self.keystream = self.cipher.encrypt(self.register);
+                self.pos = 0;
+            }
+            let input = *byte;
+            *byte ^= self.keystream[self.pos];
+            self.register[self.pos] = if decrypt { input } else { *byte };
+            self.pos += 1;
+        }
+    }
+}
+
+/// Streaming CFB128 encryption (NIST SP 800-38A, section 6.3).
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct CfbEncryptor(Cfb);
+
+impl CfbEncryptor {
+    pub fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        CfbEncryptor(Cfb::new(key, iv))
+    }
+
+    /// Encrypts `data` in place.
+    pub fn encrypt(&mut self, data: &mut [u8]) {
+        self.0.process(data, false);
+    }
+}
+
+/// Streaming CFB128 decryption.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct CfbDecryptor(Cfb);
+
+impl CfbDecryptor {
+    pub fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        CfbDecryptor(Cfb::new(key, iv))
+    }
+
+    /// Decrypts `data` in place.
+    pub fn decrypt(&mut self, data: &mut [u8]) {
+        self.0.process(data, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::modes::{
+        sp800_38a::{plaintext, IV, KEY_128},
+        test_util::for_each_backend,
+    };
+
+    fn sp800_38a_f313() -> Vec<u8> {
+        hex(&[
+            "3b3fd92eb72dad20333449f8e83cfb4a",
+            "c8a64537a0b3a93fcde3cdad9f1ce58b",
+            "26751f67a3cbb140b1808cf187a4f4df",
+            "c04b05357c5d1c0eeac4c66f9ff7f2e6",
+        ])
+    }
+
+    #[test]
+    fn test_sp800_38a_f313_cfb128_aes128() {
+        for_each_backend(|| {
+            let mut data = plaintext();
+            CfbEncryptor::new(KEY_128, IV).encrypt(&mut data);
+            assert_eq!(data, sp800_38a_f313());
+
+            CfbDecryptor::new(KEY_128, IV).decrypt(&mut data);
+            assert_eq!(data, plaintext());
+        });
+    }
+
+    #[test]
+    fn test_streaming_partial_blocks() {
+        let mut data = plaintext();
+        let mut encryptor = CfbEncryptor::new(KEY_128, IV);
+        for chunk in data.chunks_mut(7) {
+            encryptor.encrypt(chunk);
+        }
+        assert_eq!(data, sp800_38a_f313());
+
+        let mut decryptor = CfbDecryptor::new(KEY_128, IV);
+        for chunk in data.chunks_mut(13) {
+            decryptor.decrypt(chunk);
+        }
+        assert_eq!(data, plaintext());
+    }
+}
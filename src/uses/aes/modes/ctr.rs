@@ -0,0 +1,166 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{uses::aes::modes::BlockCipher, Error, Result};
+
+/// Streaming CTR mode (NIST SP 800-38A, section 6.5).
+///
+/// The low `counter_bits` bits of the counter block are a big-endian counter
+/// incremented per block; the high bits (typically a nonce) never change.
+/// Encryption and decryption are the same operation.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Ctr {
+    cipher: BlockCipher,
+    counter: u128,
+    mask: u128,
+    blocks_left: u128,
+    keystream: [u8; 16],
+    pos: usize,
+}
+
+impl Ctr {
+    /// CTR with the whole 128-bit block used as the counter.
+    pub fn new<const N: usize>(key: [u8; N], counter_block: [u8; 16]) -> Self {
+        Self::with_counter_width(key, counter_block, 128)
+    }
+
+    /// CTR with a `counter_bits`-bit counter, e.g. 32 for a 96-bit nonce.
+    ///
+    /// Panics unless `1 <= counter_bits <= 128`.
+    pub fn with_counter_width<const N: usize>(
+        key: [u8; N],
+        counter_block: [u8; 16],
+        counter_bits: u32
+    ) -> Self {
+        Self::from_cipher(BlockCipher::new(key), counter_block, counter_bits)
+    }
+
+    /// CTR over an already expanded key, for modes built on top of CTR.
+    pub(crate) fn from_cipher(cipher: BlockCipher, counter_block: [u8; 16], counter_bits: u32) -> Self {
+        assert!((1..=128).contains(&counter_bits), "Counter width must be between 1 and 128 bits");
+        let (mask, blocks_left) = if counter_bits == 128 {
+            (u128::MAX, u128::MAX)
+        } else {
+            ((1 << counter_bits) - 1, 1 << counter_bits)
+        };
+        Ctr {
+            cipher,
+            counter: u128::from_be_bytes(counter_block),
+            mask,
+            blocks_left,
+            keystream: [0; 16],
+            pos: 16,
+        }
+    }
+
+    /// XORs the keystream into `data`.
+    ///
+    /// Panics if this would wrap the counter; see [`Ctr::try_apply_keystream`].
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        self.try_apply_keystream(data).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Ctr::apply_keystream`], but leaves `data` untouched and returns
+    /// [`Error::CounterOverflow`] instead of reusing a counter value.
+    pub fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<()> {
+        let needed = data.len().saturating_sub(16 - self.pos).div_ceil(16) as u128;
+        if needed > self.blocks_left {
+            return Err(Error::CounterOverflow);
+        }
+
+        for byte in data.iter_mut() {
+            if self.pos == 16 {
+                self.next_block();
+            }
+            *byte ^= self.keystream[self.pos];
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn next_block(&mut self) {
+        self.keystream = self.cipher.encrypt(self.counter.to_be_bytes());
+        self.counter = (self.counter & !self.mask) | (self.counter.wrapping_add(1) & self.mask);
+        self.blocks_left -= 1;
+        self.pos = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::{
+        aes_encrypt,
+        modes::{
+            sp800_38a::{plaintext, KEY_128},
+            test_util::for_each_backend,
+        },
+    };
+
+    const COUNTER: [u8; 16] = [
+        0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7,
+        0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff,
+    ];
+
+    #[test]
+    fn test_sp800_38a_f51_ctr_aes128() {
+        for_each_backend(|| {
+            let expected = hex(&[
+                "874d6191b620e3261bef6864990db6ce",
+                "9806f66b7970fdff8617187bb9fffdff",
+                "5ae4df3edbd5d35e5b4f09020db03eab",
+                "1e031dda2fbe03d1792170a0f3009cee",
+            ]);
+            let mut data = plaintext();
+            Ctr::new(KEY_128, COUNTER).apply_keystream(&mut data);
+            assert_eq!(data, expected);
+
+            Ctr::new(KEY_128, COUNTER).apply_keystream(&mut data);
+            assert_eq!(data, plaintext());
+        });
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let mut one_shot = plaintext();
+        Ctr::new(KEY_128, COUNTER).apply_keystream(&mut one_shot);
+
+        let mut streamed = plaintext();
+        let mut ctr = Ctr::new(KEY_128, COUNTER);
+        for chunk in streamed.chunks_mut(5) {
+            ctr.apply_keystream(chunk);
+        }
+        assert_eq!(streamed, one_shot);
+    }
+
+    #[test]
+    fn test_counter_wraps_within_its_width() {
+        // An 8-bit counter at 0xff wraps to 0x00 without carrying into the nonce
+        let mut data = [0u8; 32];
+        Ctr::with_counter_width(KEY_128, COUNTER, 8).apply_keystream(&mut data);
+
+        let mut wrapped = COUNTER;
+        wrapped[15] = 0x00;
+        assert_eq!(data[..16], aes_encrypt(COUNTER, KEY_128));
+        assert_eq!(data[16..], aes_encrypt(wrapped, KEY_128));
+    }
+
+    #[test]
+    fn test_counter_overflow_is_rejected() {
+        let mut ctr = Ctr::with_counter_width(KEY_128, [0; 16], 2);
+        let mut data = [0u8; 65];
+        assert_eq!(ctr.try_apply_keystream(&mut data), Err(Error::CounterOverflow));
+        assert_eq!(data, [0; 65]);
+
+        // Exactly four blocks fit, including a partial last one
+        assert_eq!(ctr.try_apply_keystream(&mut data[..60]), Ok(()));
+        assert_eq!(ctr.try_apply_keystream(&mut data[60..64]), Ok(()));
+        assert_eq!(ctr.try_apply_keystream(&mut data[64..]), Err(Error::CounterOverflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "counter space exhausted")]
+    fn test_counter_overflow_should_panic() {
+        Ctr::with_counter_width(KEY_128, [0; 16], 1).apply_keystream(&mut [0; 33]);
+    }
+}
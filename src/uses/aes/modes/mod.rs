@@ -0,0 +1,322 @@
+pub mod cbc;
+pub use cbc::{cbc_decrypt, cbc_encrypt, CbcDecryptor, CbcEncryptor};
+pub mod cfb;
+pub use cfb::{CfbDecryptor, CfbEncryptor};
+pub mod ctr;
+pub use ctr::Ctr;
+pub mod ofb;
+pub use ofb::Ofb;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(target_arch = "x86_64")]
+use crate::uses::aes::AesNi;
+use crate::{
+    types::FieldElement,
+    uses::aes::{
+        aes_decrypt::decrypt_block,
+        aes_encrypt::encrypt_block,
+        expand_key,
+        expand_key::{num_rounds, MAX_ROUNDS},
+        BitslicedAes,
+    },
+};
+
+/// An expanded AES-128/192/256 key, shared by the modes so that the key
+/// schedule runs once per message rather than once per block.
+///
+/// AES-NI is used whenever the CPU supports it; otherwise the portable code,
+/// which is [`BitslicedAes`] with the `bitsliced` feature.
+///
+/// The backends keep their round keys inline, in fixed arrays, so that
+/// zeroizing wipes them in place.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub(crate) enum BlockCipher {
+    Reference(ReferenceKeys),
+    Bitsliced(BitslicedAes),
+    #[cfg(target_arch = "x86_64")]
+    AesNi(AesNi),
+}
+
+impl BlockCipher {
+    pub(crate) fn new<const N: usize>(key: [u8; N]) -> Self {
+        #[cfg(test)]
+        if let Some(backend) = test_util::forced_backend() {
+            return Self::with_backend(key, backend);
+        }
+        #[cfg(target_arch = "x86_64")]
+        if let Some(aes) = AesNi::new(key) {
+            return BlockCipher::AesNi(aes);
+        }
+        if cfg!(feature = "bitsliced") {
+            BlockCipher::Bitsliced(BitslicedAes::new(key))
+        } else {
+            BlockCipher::Reference(ReferenceKeys::new(key))
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_backend<const N: usize>(key: [u8; N], backend: Backend) -> Self {
+        match backend {
+            Backend::Reference => BlockCipher::Reference(ReferenceKeys::new(key)),
+            Backend::Bitsliced => BlockCipher::Bitsliced(BitslicedAes::new(key)),
+            #[cfg(target_arch = "x86_64")]
+            Backend::AesNi => BlockCipher::AesNi(AesNi::new(key).expect("AES-NI is supported")),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn backend(&self) -> Backend {
+        match self {
+            BlockCipher::Reference(_) => Backend::Reference,
+            BlockCipher::Bitsliced(_) => Backend::Bitsliced,
+            #[cfg(target_arch = "x86_64")]
+            BlockCipher::AesNi(_) => Backend::AesNi,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn keys_are_zero(&self) -> bool {
+        match self {
+            BlockCipher::Reference(keys) => keys.round_keys.iter().all(|k| k.0 == 0),
+            BlockCipher::Bitsliced(aes) => aes.keys_are_zero(),
+            #[cfg(target_arch = "x86_64")]
+            BlockCipher::AesNi(aes) => aes.keys_are_zero(),
+        }
+    }
+
+    pub(crate) fn encrypt(&self, block: [u8; 16]) -> [u8; 16] {
+        match self {
+            BlockCipher::Reference(keys) => encrypt_block(block, keys.round_keys()),
+            BlockCipher::Bitsliced(aes) => aes.encrypt_block(block),
+            #[cfg(target_arch = "x86_64")]
+            BlockCipher::AesNi(aes) => aes.encrypt_block(block),
+        }
+    }
+
+    pub(crate) fn decrypt(&self, block: [u8; 16]) -> [u8; 16] {
+        match self {
+            BlockCipher::Reference(keys) => decrypt_block(block, keys.round_keys()),
+            BlockCipher::Bitsliced(aes) => aes.decrypt_block(block),
+            #[cfg(target_arch = "x86_64")]
+            BlockCipher::AesNi(aes) => aes.decrypt_block(block),
+        }
+    }
+
+    /// Encrypts `blocks` in place; the bitsliced backend does eight at a time.
+    pub(crate) fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self {
+            BlockCipher::Bitsliced(aes) => aes.encrypt_blocks(blocks),
+            _ => blocks.iter_mut().for_each(|block| *block = self.encrypt(*block)),
+        }
+    }
+
+    /// Decrypts `blocks` in place; the bitsliced backend does eight at a time.
+    pub(crate) fn decrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        match self {
+            BlockCipher::Bitsliced(aes) => aes.decrypt_blocks(blocks),
+            _ => blocks.iter_mut().for_each(|block| *block = self.decrypt(*block)),
+        }
+    }
+}
+
+/// Round keys of the table-based reference cipher. They are kept in a fixed
+/// array so that zeroizing wipes them in place.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub(crate) struct ReferenceKeys {
+    /// Only the first `16 * (rounds + 1)` bytes are used.
+    round_keys: [FieldElement; 16 * (MAX_ROUNDS + 1)],
+    #[zeroize(skip)]
+    rounds: usize,
+}
+
+impl ReferenceKeys {
+    fn new<const N: usize>(key: [u8; N]) -> Self {
+        let mut expanded = expand_key(key);
+        let mut round_keys = [FieldElement::ZERO; 16 * (MAX_ROUNDS + 1)];
+        round_keys[..expanded.len()].copy_from_slice(&expanded);
+        expanded.zeroize();
+        ReferenceKeys { round_keys, rounds: num_rounds(N) }
+    }
+
+    fn round_keys(&self) -> &[FieldElement] {
+        &self.round_keys[..16 * (self.rounds + 1)]
+    }
+}
+
+/// The [`BlockCipher`] variants, so tests can run against each of them.
+#[cfg(test)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Backend {
+    Reference,
+    Bitsliced,
+    #[cfg(target_arch = "x86_64")]
+    AesNi,
+}
+
+#[cfg(test)]
+impl Backend {
+    /// The backends usable on the running CPU.
+    pub(crate) fn available() -> Vec<Backend> {
+        let mut backends = vec![Backend::Reference, Backend::Bitsliced];
+        #[cfg(target_arch = "x86_64")]
+        if AesNi::is_supported() {
+            backends.push(Backend::AesNi);
+        }
+        backends
+    }
+}
+
+/// XORs `b` into `a`.
+pub(crate) fn xor_block(a: &mut [u8; 16], b: &[u8; 16]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x ^= y;
+    }
+}
+
+/// Compares two byte strings in time depending only on their lengths.
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Block-aligned plaintext and ciphertext from NIST SP 800-38A, Appendix F.
+#[cfg(test)]
+pub(crate) mod sp800_38a {
+    use crate::test_util::hex;
+
+    pub(crate) const KEY_128: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+    ];
+    pub(crate) const KEY_256: [u8; 32] = [
+        0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe,
+        0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77, 0x81,
+        0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7,
+        0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14, 0xdf, 0xf4,
+    ];
+    pub(crate) const IV: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    ];
+
+    pub(crate) fn plaintext() -> Vec<u8> {
+        hex(&[
+            "6bc1bee22e409f96e93d7e117393172a",
+            "ae2d8a571e03ac9c9eb76fac45af8e51",
+            "30c81c46a35ce411e5fbc1191a0a52ef",
+            "f69f2445df4f9b17ad2b417be66c3710",
+        ])
+    }
+
+}
+
+/// Test helpers shared by the AES tests: AEAD round trips and forcing a
+/// [`BlockCipher`] backend.
+#[cfg(test)]
+pub(crate) mod test_util {
+    use std::{
+        cell::Cell,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use super::Backend;
+    use crate::{
+        uses::aes::{AesCcm, AesGcm, AesGcmSiv},
+        Result,
+    };
+
+    thread_local! {
+        static FORCED_BACKEND: Cell<Option<Backend>> = const { Cell::new(None) };
+    }
+
+    /// The backend [`super::BlockCipher::new`] must use on this thread, if any.
+    pub(crate) fn forced_backend() -> Option<Backend> {
+        FORCED_BACKEND.with(Cell::get)
+    }
+
+    /// Runs `f` once per available backend, with every [`super::BlockCipher`]
+    /// created inside it forced onto that backend. A failure is re-raised
+    /// with the backend's name in its message.
+    pub(crate) fn for_each_backend(mut f: impl FnMut()) {
+        for backend in Backend::available() {
+            FORCED_BACKEND.with(|forced| forced.set(Some(backend)));
+            let result = panic::catch_unwind(AssertUnwindSafe(&mut f));
+            FORCED_BACKEND.with(|forced| forced.set(None));
+
+            if let Err(payload) = result {
+                let message = payload
+                    .downcast_ref::<String>()
+                    .map(String::as_str)
+                    .or_else(|| payload.downcast_ref::<&str>().copied())
+                    .unwrap_or("non-string panic payload");
+                panic!("{backend:?} AES backend: {message}");
+            }
+        }
+    }
+
+    /// The combined-tag interface of the AEAD modes.
+    pub(crate) trait Aead {
+        fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>>;
+        fn open(&self, nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>>;
+    }
+
+    macro_rules! impl_aead {
+        ($($t:ty),*) => {$(
+            impl Aead for $t {
+                fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>> {
+                    <$t>::seal(self, nonce, aad, plaintext)
+                }
+                fn open(&self, nonce: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>> {
+                    <$t>::open(self, nonce, aad, sealed)
+                }
+            }
+        )*};
+    }
+    impl_aead!(AesGcm, AesGcmSiv, AesCcm);
+
+    /// Checks that sealing gives `sealed` and opening it gives back `plaintext`.
+    pub(crate) fn check_aead(aead: &impl Aead, nonce: &[u8], aad: &[u8], plaintext: &[u8], sealed: &[u8]) {
+        assert_eq!(aead.seal(nonce, aad, plaintext).unwrap(), sealed);
+        assert_eq!(aead.open(nonce, aad, sealed).unwrap(), plaintext);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::{
+        sp800_38a::{plaintext, KEY_128},
+        test_util::{for_each_backend, forced_backend},
+    };
+
+    #[test]
+    fn test_default_backend() {
+        #[cfg(target_arch = "x86_64")]
+        if AesNi::is_supported() {
+            assert_eq!(BlockCipher::new(KEY_128).backend(), Backend::AesNi);
+            return;
+        }
+        let expected = if cfg!(feature = "bitsliced") { Backend::Bitsliced } else { Backend::Reference };
+        assert_eq!(BlockCipher::new(KEY_128).backend(), expected);
+    }
+
+    #[test]
+    fn test_forced_backends_agree() {
+        let block: [u8; 16] = plaintext()[..16].try_into().unwrap();
+        let expected = BlockCipher::new(KEY_128).encrypt(block);
+        let mut seen = Vec::new();
+        for_each_backend(|| {
+            let aes = BlockCipher::new(KEY_128);
+            assert_eq!(Some(aes.backend()), forced_backend());
+            assert_eq!(aes.encrypt(block), expected);
+            assert_eq!(aes.decrypt(expected), block);
+            seen.push(aes.backend());
+        });
+        assert_eq!(seen, Backend::available());
+        assert_eq!(forced_backend(), None);
+    }
+}
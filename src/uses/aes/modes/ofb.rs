@@ -0,0 +1,63 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::uses::aes::modes::BlockCipher;
+
+/// Streaming OFB mode (NIST SP 800-38A, section 6.4).
+///
+/// The keystream is the IV encrypted repeatedly, independent of the data, so
+/// encryption and decryption are the same operation. Never reuse an IV.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Ofb {
+    cipher: BlockCipher,
+    keystream: [u8; 16],
+    pos: usize,
+}
+
+impl Ofb {
+    pub fn new<const N: usize>(key: [u8; N], iv: [u8; 16]) -> Self {
+        Ofb { cipher: BlockCipher::new(key), keystream: iv, pos: 16 }
+    }
+
+    /// XORs the keystream into `data`.
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        for byte in data.iter_mut() {
+            if self.pos == 16 {
+                self.keystream = self.cipher.encrypt(self.keystream);
+                self.pos = 0;
+            }
+            *byte ^= self.keystream[self.pos];
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::hex;
+    use crate::uses::aes::modes::{
+        sp800_38a::{plaintext, IV, KEY_128},
+        test_util::for_each_backend,
+    };
+
+    #[test]
+    fn test_sp800_38a_f41_ofb_aes128() {
+        for_each_backend(|| {
+            let expected = hex(&[
+                "3b3fd92eb72dad20333449f8e83cfb4a",
+                "7789508d16918f03f53c52dac54ed825",
+                "9740051e9c5fecf64344f7a82260edcc",
+                "304c6528f659c77866a510d9c1d6ae5e",
+            ]);
+            let mut data = plaintext();
+            Ofb::new(KEY_128, IV).apply_keystream(&mut data);
+            assert_eq!(data, expected);
+
+            let mut ofb = Ofb::new(KEY_128, IV);
+            for chunk in data.chunks_mut(3) {
+                ofb.apply_keystream(chunk);
+            }
+            assert_eq!(data, plaintext());
+        });
+    }
+}
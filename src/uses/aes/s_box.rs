@@ -19,7 +19,7 @@ pub fn inv_s_box(input: FieldElement) -> FieldElement {
     let s = input.0;
     // 1. Inverse Affine Transformation
     // This is the constant-time version of the AES inverse affine map
-    let out = (s.rotate_right(1) ^ s.rotate_right(3) ^ s.rotate_right(6)) ^ 0x05;
+    let out = (s.rotate_left(1) ^ s.rotate_left(3) ^ s.rotate_left(6)) ^ 0x05;
     
     // 2. Multiplicative Inverse (using your existing logic)
     // 0 is still mapped to 0 in AES
@@ -41,4 +41,12 @@ fn s_box_0x01() {
 
 pub fn is_sbox_inverse(x: u8) -> bool {
     inv_s_box(s_box(FieldElement(x))) == FieldElement(x)
-}
\ No newline at end of file
+}
+
+#[test]
+fn inv_s_box_roundtrip() {
+    assert_eq!(inv_s_box(FieldElement(0x7C)).0, 0x01);
+    for x in 0..=255u8 {
+        assert!(is_sbox_inverse(x), "inv_s_box(s_box(x)) != x for x = {}", x);
+    }
+}
@@ -1,11 +1,11 @@
 
-use crate::types::FieldElement;
+use crate::{galois_fields::gf2n::AES_POLY, types::FieldElement};
 
 // A high-performance "Multiply by 2" for AES
 pub(crate)fn xtime(a: FieldElement) -> FieldElement {
     let raw = a.0;
     let res = if raw & 0x80 != 0 {
-        (raw << 1) ^ 0x1B
+        (raw << 1) ^ (AES_POLY as u8)
     } else {
         raw << 1
     };
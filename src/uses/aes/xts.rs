@@ -0,0 +1,220 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    galois_fields::gf2_128::{gf2_128_mul_x, GCM_REDUCTION},
+    uses::aes::modes::{xor_block, BlockCipher},
+    Error,
+    Result,
+};
+
+/// Largest data unit IEEE 1619 allows: 2^20 blocks.
+const MAX_DATA_UNIT_LEN: usize = 16 << 20;
+
+/// XTS-AES (IEEE 1619, NIST SP 800-38E) for encrypting storage sectors.
+///
+/// Each data unit (sector) is encrypted independently under a tweak derived
+/// from its sector number; units whose length is not a multiple of 16 use
+/// ciphertext stealing, so the ciphertext is exactly as long as the plaintext.
+/// XTS provides no authentication.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AesXts {
+    data_cipher: BlockCipher,
+    tweak_cipher: BlockCipher,
+}
+
+impl AesXts {
+    /// Builds XTS-AES-128 or XTS-AES-256 from the two halves of the XTS key.
+    ///
+    /// SP 800-38E requires `data_key != tweak_key`; this is not enforced so
+    /// the IEEE 1619 test vectors with equal keys remain usable.
+    pub fn new<const N: usize>(data_key: [u8; N], tweak_key: [u8; N]) -> Self {
+        const { assert!(N == 16 || N == 32, "XTS-AES keys are 16 or 32 bytes each") };
+        AesXts { data_cipher: BlockCipher::new(data_key), tweak_cipher: BlockCipher::new(tweak_key) }
+    }
+
+    /// Encrypts one sector in place, using its number as the tweak.
+    pub fn encrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<()> {
+        self.encrypt_with_tweak(sector.to_le_bytes(), data)
+    }
+
+    /// Decrypts one sector in place.
+    pub fn decrypt_sector(&self, sector: u128, data: &mut [u8]) -> Result<()> {
+        self.decrypt_with_tweak(sector.to_le_bytes(), data)
+    }
+
+    /// Encrypts a data unit in place under an explicit 16-byte tweak value.
+    pub fn encrypt_with_tweak(&self, tweak: [u8; 16], data: &mut [u8]) -> Result<()> {
+        check_length(data.len())?;
+        let mut t = self.initial_tweak(tweak);
+        let (full, rest) = split_for_stealing(data);
+
+        let (body, last_full) = full.split_at_mut(full.len() - 16);
+        for block in body.chunks_exact_mut(16) {
+            self.process_block(block, t, false);
+            t = mul_alpha(t);
+        }
+        self.process_block(last_full, t, false);
+
+        if !rest.is_empty() {
+            // Ciphertext stealing: the partial block takes the head of the
+            // last full ciphertext block, whose tail pads the partial plaintext
+            let b = rest.len();
+            let mut stolen = [0u8; 16];
+            stolen[..b].copy_from_slice(rest);
+            stolen[b..].copy_from_slice(&last_full[b..]);
+            rest.copy_from_slice(&last_full[..b]);
+
+            self.process_block(&mut stolen, mul_alpha(t), false);
+            last_full.copy_from_slice(&stolen);
+            stolen.zeroize();
+        }
+        Ok(())
+    }
+
+    /// Decrypts a data unit in place under an explicit 16-byte tweak value.
+    pub fn decrypt_with_tweak(&self, tweak: [u8; 16], data: &mut [u8]) -> Result<()> {
+        check_length(data.len())?;
+        let mut t = self.initial_tweak(tweak);
+        let (full, rest) = split_for_stealing(data);
+
+        let (body, last_full) = full.split_at_mut(full.len() - 16);
+        for block in body.chunks_exact_mut(16) {
+            self.process_block(block, t, true);
+            t = mul_alpha(t);
+        }
+
+        if rest.is_empty() {
+            self.process_block(last_full, t, true);
+        } else {
+            // The last full ciphertext block was produced under the next tweak
+            self.process_block(last_full, mul_alpha(t), true);
+
+            let b = rest.len();
+            let mut stolen = [0u8; 16];
+            stolen[..b].copy_from_slice(rest);
+            stolen[b..].copy_from_slice(&last_full[b..]);
+            rest.copy_from_slice(&last_full[..b]);
+
+            self.process_block(&mut stolen, t, true);
+            last_full.copy_from_slice(&stolen);
+            stolen.zeroize();
+        }
+        Ok(())
+    }
+
+    fn initial_tweak(&self, tweak: [u8; 16]) -> u128 {
+        u128::from_le_bytes(self.tweak_cipher.encrypt(tweak))
+    }
+
+    /// `C = E(P ^ T) ^ T`, or the inverse when `decrypt` is set.
+    fn process_block(&self, block: &mut [u8], t: u128, decrypt: bool) {
+        let t = t.to_le_bytes();
+        let mut x: [u8; 16] = (&*block).try_into().expect("XTS blocks are 16 bytes");
+        xor_block(&mut x, &t);
+        x = if decrypt { self.data_cipher.decrypt(x) } else { self.data_cipher.encrypt(x) };
+        xor_block(&mut x, &t);
+        block.copy_from_slice(&x);
+        x.zeroize();
+    }
+}
+
+/// Multiplication of the tweak by the primitive element alpha = x, with
+/// blocks read little-endian as in IEEE 1619, section 5.2.
+fn mul_alpha(t: u128) -> u128 {
+    gf2_128_mul_x::<GCM_REDUCTION>(t)
+}
+
+fn check_length(len: usize) -> Result<()> {
+    if len < 16 {
+        return Err(Error::DataUnitTooShort);
+    }
+    if len > MAX_DATA_UNIT_LEN {
+        return Err(Error::MessageTooLong);
+    }
+    Ok(())
+}
+
+/// Splits into whole blocks and the trailing partial block, if any.
+fn split_for_stealing(data: &mut [u8]) -> (&mut [u8], &mut [u8]) {
+    let full_len = data.len() - data.len() % 16;
+    data.split_at_mut(full_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_util::{array, hex},
+        uses::aes::modes::test_util::for_each_backend,
+    };
+
+    fn check(xts: &AesXts, sector: u128, plaintext: &[u8], ciphertext: &[u8]) {
+        let mut data = plaintext.to_vec();
+        xts.encrypt_sector(sector, &mut data).unwrap();
+        assert_eq!(data, ciphertext);
+        xts.decrypt_sector(sector, &mut data).unwrap();
+        assert_eq!(data, plaintext);
+    }
+
+    #[test]
+    fn test_ieee1619_vector_1_and_2() {
+        for_each_backend(|| {
+            let xts = AesXts::new([0u8; 16], [0u8; 16]);
+            let expected = hex(&["917cf69ebd68b2ec9b9fe9a3eadda692cd43d2f59598ed858c02c2652fbf922e"]);
+            check(&xts, 0, &[0; 32], &expected);
+
+            let xts = AesXts::new([0x11u8; 16], [0x22u8; 16]);
+            let expected = hex(&["c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0"]);
+            check(&xts, 0x33_3333_3333, &[0x44; 32], &expected);
+        });
+    }
+
+    #[test]
+    fn test_ieee1619_vector_10_aes256() {
+        for_each_backend(|| {
+            let xts = AesXts::new(
+                array::<32>("2718281828459045235360287471352662497757247093699959574966967627"),
+                array::<32>("3141592653589793238462643383279502884197169399375105820974944592"),
+            );
+            let plaintext: Vec<u8> = (0..512).map(|i| i as u8).collect();
+            let mut data = plaintext.clone();
+            xts.encrypt_sector(0xff, &mut data).unwrap();
+            assert_eq!(data[..32], hex(&["1c3b3a102f770386e4836c99e370cf9bea00803f5e482357a4ae12d414a3e63b"]));
+            assert_eq!(data[480..], hex(&["773dad38014bd2092fa755c824bb5e54c4f36ffda9fcea70b9c6e693e148c151"]));
+            xts.decrypt_sector(0xff, &mut data).unwrap();
+            assert_eq!(data, plaintext);
+        });
+    }
+
+    #[test]
+    fn test_ciphertext_stealing() {
+        // Partial final blocks, cross-checked against OpenSSL's XTS-AES-128
+        let xts = AesXts::new(
+            array::<16>("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0"),
+            array::<16>("bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0"),
+        );
+        let plaintext: Vec<u8> = (0..20).collect();
+        check(&xts, 0x9a_7856_3412, &plaintext[..17], &hex(&["641610679dcbf92e505c41333fb06c2a95"]));
+        check(&xts, 0x9a_7856_3412, &plaintext, &hex(&["a8ba0048d75084603eb8423a09b7bf7595c871f6"]));
+    }
+
+    #[test]
+    fn test_roundtrip_all_lengths() {
+        let xts = AesXts::new([1u8; 32], [2u8; 32]);
+        for len in 16..=80 {
+            let plaintext: Vec<u8> = (0..len as u8).collect();
+            let mut data = plaintext.clone();
+            xts.encrypt_sector(7, &mut data).unwrap();
+            assert_ne!(data, plaintext);
+            xts.decrypt_sector(7, &mut data).unwrap();
+            assert_eq!(data, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_rejects_short_data_unit() {
+        let xts = AesXts::new([0u8; 16], [1u8; 16]);
+        assert_eq!(xts.encrypt_sector(0, &mut [0; 15]), Err(Error::DataUnitTooShort));
+        assert_eq!(xts.decrypt_sector(0, &mut []), Err(Error::DataUnitTooShort));
+    }
+}
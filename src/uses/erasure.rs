@@ -0,0 +1,268 @@
+use crate::{matrix::Matrix, types::FieldElement, Error, Result};
+
+/// How the parity rows of the encoding matrix are built.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EncodingMatrix {
+    /// A Vandermonde matrix made systematic by multiplying with the inverse of
+    /// its top square (the construction of Backblaze-style RS coders).
+    Vandermonde,
+    /// An identity on top of a Cauchy matrix, MDS by construction.
+    Cauchy,
+}
+
+/// A systematic k-of-n erasure code over GF(256) for striping data across
+/// `k` data shards and `m` parity shards.
+///
+/// Any `k` of the `k + m` shards recover the rest, so up to `m` lost shards
+/// (disks) are tolerated. Shards are byte vectors of equal length; byte `b` of
+/// every shard forms one codeword.
+#[derive(Clone, Debug)]
+pub struct ErasureCoder {
+    data_shards: usize,
+    parity_shards: usize,
+    /// `(k + m) x k` encoding matrix whose top `k` rows are the identity.
+    matrix: Matrix<FieldElement>,
+}
+
+impl ErasureCoder {
+    /// Requires `k >= 1`, `m >= 1` and `k + m <= 256`.
+    pub fn new(data_shards: usize, parity_shards: usize, kind: EncodingMatrix) -> Result<Self> {
+        let total = data_shards + parity_shards;
+        if data_shards == 0 || parity_shards == 0 || total > 256 {
+            return Err(Error::InvalidCodeParameters);
+        }
+        let points: Vec<FieldElement> = (0..total).map(|i| FieldElement(i as u8)).collect();
+
+        let matrix = match kind {
+            EncodingMatrix::Vandermonde => {
+                // Every k rows of a Vandermonde matrix on distinct points are
+                // independent, which right-multiplication preserves
+                let vandermonde = Matrix::vandermonde(&points, data_shards);
+                let top: Vec<usize> = (0..data_shards).collect();
+                let top_inv = vandermonde
+                    .select_rows(&top)
+                    .inverse()
+                    .expect("a square Vandermonde matrix on distinct points is invertible");
+                &vandermonde * &top_inv
+            }
+            EncodingMatrix::Cauchy => {
+                // x_i = k + i and y_j = j are disjoint, so no entry divides by zero
+                let (ys, xs) = points.split_at(data_shards);
+                let cauchy = Matrix::cauchy(xs, ys).expect("Cauchy points are distinct");
+                let identity = Matrix::identity(data_shards);
+                Matrix::from_rows(
+                    (0..data_shards)
+                        .map(|i| identity.row(i).to_vec())
+                        .chain((0..parity_shards).map(|i| cauchy.row(i).to_vec()))
+                        .collect(),
+                )
+            }
+        };
+        Ok(ErasureCoder { data_shards, parity_shards, matrix })
+    }
+
+    pub fn data_shards(&self) -> usize {
+        self.data_shards
+    }
+
+    pub fn parity_shards(&self) -> usize {
+        self.parity_shards
+    }
+
+    pub fn total_shards(&self) -> usize {
+        self.data_shards + self.parity_shards
+    }
+
+    /// Splits `data` into `k` zero-padded data shards followed by `m` parity
+    /// shards. Keep `data.len()` to undo the padding in [`ErasureCoder::join`].
+    pub fn split(&self, data: &[u8]) -> Vec<Vec<u8>> {
+        let shard_len = data.len().div_ceil(self.data_shards).max(1);
+        let mut shards: Vec<Vec<u8>> = (0..self.total_shards())
+            .map(|i| {
+                let start = (i * shard_len).min(data.len());
+                let end = ((i + 1) * shard_len).min(data.len());
+                let mut shard = if i < self.data_shards { data[start..end].to_vec() } else { Vec::new() };
+                shard.resize(shard_len, 0);
+                shard
+            })
+            .collect();
+        self.encode(&mut shards).expect("shards were built with consistent lengths");
+        shards
+    }
+
+    /// Recomputes the parity shards from the data shards, in place.
+    pub fn encode(&self, shards: &mut [Vec<u8>]) -> Result<()> {
+        self.check_shards(shards.iter().map(Some))?;
+        let (data, parity) = shards.split_at_mut(self.data_shards);
+        for (i, shard) in parity.iter_mut().enumerate() {
+            *shard = combine(self.matrix.row(self.data_shards + i), data);
+        }
+        Ok(())
+    }
+
+    /// Whether the parity shards are consistent with the data shards.
+    pub fn verify(&self, shards: &[Vec<u8>]) -> Result<bool> {
+        self.check_shards(shards.iter().map(Some))?;
+        let (data, parity) = shards.split_at(self.data_shards);
+        Ok(parity
+            .iter()
+            .enumerate()
+            .all(|(i, shard)| combine(self.matrix.row(self.data_shards + i), data) == *shard))
+    }
+
+    /// Fills in every `None` shard from any `k` present ones.
+    pub fn reconstruct(&self, shards: &mut [Option<Vec<u8>>]) -> Result<()> {
+        self.check_shards(shards.iter().map(Option::as_ref))?;
+        let present: Vec<usize> = (0..shards.len()).filter(|&i| shards[i].is_some()).collect();
+        if present.len() < self.data_shards {
+            return Err(Error::TooFewShards);
+        }
+        if present.len() == shards.len() {
+            return Ok(());
+        }
+
+        // The rows of the surviving shards map the data to them; invert to
+        // recover the data, then re-encode whatever is missing
+        let present = &present[..self.data_shards];
+        let decode = self
+            .matrix
+            .select_rows(present)
+            .inverse()
+            .expect("any k rows of an MDS encoding matrix are independent");
+        let survivors: Vec<Vec<u8>> = present.iter().map(|&i| shards[i].clone().expect("present")).collect();
+        let data: Vec<Vec<u8>> = (0..self.data_shards).map(|i| combine(decode.row(i), &survivors)).collect();
+
+        for (i, shard) in shards.iter_mut().enumerate() {
+            if shard.is_none() {
+                *shard = Some(if i < self.data_shards { data[i].clone() } else { combine(self.matrix.row(i), &data) });
+            }
+        }
+        Ok(())
+    }
+
+    /// Concatenates the data shards and truncates to the original length.
+    pub fn join(&self, shards: &[Vec<u8>], len: usize) -> Result<Vec<u8>> {
+        self.check_shards(shards.iter().map(Some))?;
+        let mut data: Vec<u8> = shards[..self.data_shards].concat();
+        if len > data.len() {
+            return Err(Error::CodeLengthMismatch);
+        }
+        data.truncate(len);
+        Ok(data)
+    }
+
+    /// Checks the shard count and that all present shards have one length.
+    fn check_shards<'a>(&self, shards: impl ExactSizeIterator<Item = Option<&'a Vec<u8>>>) -> Result<()> {
+        if shards.len() != self.total_shards() {
+            return Err(Error::CodeLengthMismatch);
+        }
+        let mut lengths = shards.flatten().map(Vec::len);
+        match lengths.next() {
+            Some(first) if lengths.any(|l| l != first) => Err(Error::ShardLengthMismatch),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `sum_j row[j] * shards[j]`, bytewise.
+fn combine(row: &[FieldElement], shards: &[Vec<u8>]) -> Vec<u8> {
+    let mut out = vec![0u8; shards[0].len()];
+    for (&c, shard) in row.iter().zip(shards) {
+        if c == FieldElement::ZERO {
+            continue;
+        }
+        for (o, &b) in out.iter_mut().zip(shard) {
+            *o ^= (c * FieldElement(b)).0;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{seq::index::sample, Rng, RngCore};
+
+    const KINDS: [EncodingMatrix; 2] = [EncodingMatrix::Vandermonde, EncodingMatrix::Cauchy];
+
+    #[test]
+    fn test_matrix_is_systematic() {
+        for kind in KINDS {
+            let coder = ErasureCoder::new(4, 3, kind).unwrap();
+            let top: Vec<usize> = (0..4).collect();
+            assert_eq!(coder.matrix.select_rows(&top), Matrix::identity(4));
+        }
+    }
+
+    #[test]
+    fn test_every_k_subset_reconstructs() {
+        for kind in KINDS {
+            let coder = ErasureCoder::new(3, 3, kind).unwrap();
+            let data = b"stripe me across six disks".to_vec();
+            let shards = coder.split(&data);
+            assert!(coder.verify(&shards).unwrap());
+
+            for mask in 0u32..64 {
+                if mask.count_ones() < 3 {
+                    continue;
+                }
+                let mut partial: Vec<Option<Vec<u8>>> =
+                    shards.iter().enumerate().map(|(i, s)| (mask >> i & 1 == 1).then(|| s.clone())).collect();
+                coder.reconstruct(&mut partial).unwrap();
+                let restored: Vec<Vec<u8>> = partial.into_iter().map(Option::unwrap).collect();
+                assert_eq!(restored, shards);
+                assert_eq!(coder.join(&restored, data.len()).unwrap(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_losses_in_wide_codes() {
+        let mut rng = rand::thread_rng();
+        for kind in KINDS {
+            for (k, m) in [(17, 3), (10, 4), (200, 56), (1, 1)] {
+                let coder = ErasureCoder::new(k, m, kind).unwrap();
+                let mut data = vec![0u8; rng.gen_range(0..4096)];
+                rng.fill_bytes(&mut data);
+
+                let shards = coder.split(&data);
+                let mut partial: Vec<Option<Vec<u8>>> = shards.iter().cloned().map(Some).collect();
+                for i in sample(&mut rng, k + m, m) {
+                    partial[i] = None;
+                }
+                coder.reconstruct(&mut partial).unwrap();
+                let restored: Vec<Vec<u8>> = partial.into_iter().map(Option::unwrap).collect();
+                assert_eq!(coder.join(&restored, data.len()).unwrap(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_detects_corruption() {
+        let coder = ErasureCoder::new(4, 2, EncodingMatrix::Cauchy).unwrap();
+        let mut shards = coder.split(&[0x5a; 100]);
+        assert!(coder.verify(&shards).unwrap());
+        shards[1][7] ^= 1;
+        assert!(!coder.verify(&shards).unwrap());
+        coder.encode(&mut shards).unwrap();
+        assert!(coder.verify(&shards).unwrap());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(ErasureCoder::new(0, 2, EncodingMatrix::Cauchy).unwrap_err(), Error::InvalidCodeParameters);
+        assert_eq!(ErasureCoder::new(200, 57, EncodingMatrix::Vandermonde).unwrap_err(), Error::InvalidCodeParameters);
+
+        let coder = ErasureCoder::new(2, 2, EncodingMatrix::Vandermonde).unwrap();
+        let shards = coder.split(b"abcd");
+        assert_eq!(coder.verify(&shards[..3]), Err(Error::CodeLengthMismatch));
+
+        let mut uneven = shards.clone();
+        uneven[3].push(0);
+        assert_eq!(coder.verify(&uneven), Err(Error::ShardLengthMismatch));
+
+        let mut partial = vec![Some(shards[0].clone()), None, None, None];
+        assert_eq!(coder.reconstruct(&mut partial), Err(Error::TooFewShards));
+        assert_eq!(coder.join(&shards, 5), Err(Error::CodeLengthMismatch));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    galois_fields::gf2n::{gf2n_pow, RS_POLY},
+    polynomial::Polynomial,
+    types::RsField,
+    Error,
+    Result,
+};
+
+/// The primitive element alpha = x of GF(2^8) under 0x11D.
+const ALPHA: RsField = RsField::new(0x02);
+
+/// A systematic Reed–Solomon code over GF(256) (polynomial 0x11D).
+///
+/// Codewords are `n` bytes: the `k` data bytes followed by `n - k` parity
+/// bytes, with the first byte as the highest-degree coefficient (the QR code
+/// convention). Codes with `n < 255` are shortened codes. Up to `t` errors
+/// and `f` erasures are corrected whenever `2t + f <= n - k`.
+#[derive(Clone, Debug)]
+pub struct ReedSolomon {
+    n: usize,
+    k: usize,
+    first_root: usize,
+    /// beta = alpha^root_step; the generator's roots are beta^(first_root + i).
+    beta: RsField,
+    generator: Polynomial<RsField>,
+}
+
+impl ReedSolomon {
+    /// An `(n, k)` code with generator roots `alpha^0 .. alpha^(n-k-1)`, as
+    /// used by QR codes.
+    pub fn new(n: usize, k: usize) -> Result<Self> {
+        Self::with_roots(n, k, 0, 1)
+    }
+
+    /// An `(n, k)` code whose generator has the roots
+    /// `beta^first_root, ..., beta^(first_root + n - k - 1)` with
+    /// `beta = alpha^root_step`; `root_step` must be coprime to 255.
+    pub fn with_roots(n: usize, k: usize, first_root: u8, root_step: u8) -> Result<Self> {
+        if k == 0 || k >= n || n > 255 || gcd(root_step as usize, 255) != 1 {
+            return Err(Error::InvalidCodeParameters);
+        }
+        let beta = pow(ALPHA, root_step as usize);
+        let first_root = first_root as usize;
+
+        let generator = (0..n - k).fold(Polynomial::one(), |g, i| {
+            &g * &Polynomial::new(vec![pow(beta, first_root + i), RsField::ONE])
+        });
+        Ok(ReedSolomon { n, k, first_root, beta, generator })
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Number of parity bytes, `n - k`.
+    pub fn parity_len(&self) -> usize {
+        self.n - self.k
+    }
+
+    /// Encodes `k` data bytes into an `n`-byte codeword `data || parity`.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() != self.k {
+            return Err(Error::CodeLengthMismatch);
+        }
+        // parity = data(x) * x^(n-k) mod g(x)
+        let shifted = Polynomial::new(
+            core::iter::repeat_n(RsField::ZERO, self.parity_len())
+                .chain(data.iter().rev().map(|&b| RsField::new(b as u64)))
+                .collect(),
+        );
+        let (_, remainder) = shifted.div_rem(&self.generator);
+
+        let mut codeword = data.to_vec();
+        codeword.extend((0..self.parity_len()).rev().map(|i| remainder.coefficient(i).value() as u8));
+        Ok(codeword)
+    }
+
+    /// The `n - k` syndromes `S_i = c(beta^(first_root + i))`; all zero
+    /// exactly when `codeword` is a valid codeword.
+    pub fn syndromes(&self, codeword: &[u8]) -> Result<Vec<RsField>> {
+        if codeword.len() != self.n {
+            return Err(Error::CodeLengthMismatch);
+        }
+        Ok((0..self.parity_len())
+            .map(|i| {
+                let root = pow(self.beta, self.first_root + i);
+                codeword.iter().fold(RsField::ZERO, |acc, &c| acc * root + RsField::new(c as u64))
+            })
+            .collect())
+    }
+
+    /// Corrects `codeword` and returns its `k` data bytes.
+    ///
+    /// `erasures` lists byte positions known to be unreliable.
+    pub fn decode(&self, codeword: &[u8], erasures: &[usize]) -> Result<Vec<u8>> {
+        let mut corrected = codeword.to_vec();
+        self.correct(&mut corrected, erasures)?;
+        corrected.truncate(self.k);
+        Ok(corrected)
+    }
+
+    /// Corrects `codeword` in place and returns the number of bytes changed.
+    ///
+    /// On [`Error::Uncorrectable`] the codeword is left untouched.
+    pub fn correct(&self, codeword: &mut [u8], erasures: &[usize]) -> Result<usize> {
+        let syndromes = self.syndromes(codeword)?;
+        for (i, &e) in erasures.iter().enumerate() {
+            if e >= self.n || erasures[..i].contains(&e) {
+                return Err(Error::InvalidErasure);
+            }
+        }
+        if erasures.len() > self.parity_len() {
+            return Err(Error::Uncorrectable);
+        }
+        if syndromes.iter().all(|s| *s == RsField::ZERO) {
+            return Ok(0);
+        }
+
+        // Erasure locator: prod (1 - X_j x) over the erased positions
+        let erasure_locator = erasures.iter().fold(Polynomial::one(), |gamma, &p| {
+            &gamma * &Polynomial::new(vec![RsField::ONE, self.locator(p)])
+        });
+        let locator = self.berlekamp_massey(&syndromes, erasure_locator, erasures.len());
+
+        // Error evaluator: S(x) * Lambda(x) mod x^(n-k)
+        let syndrome_poly = Polynomial::new(syndromes);
+        let mut evaluator = (&syndrome_poly * &locator).into_coefficients();
+        evaluator.truncate(self.parity_len());
+        let evaluator = Polynomial::new(evaluator);
+
+        let positions = self.chien_search(&locator);
+        if Some(positions.len()) != locator.degree() {
+            return Err(Error::Uncorrectable);
+        }
+
+        let mut candidate = codeword.to_vec();
+        let derivative = formal_derivative(&locator);
+        for &p in &positions {
+            // Forney: e = X^(1 - first_root) * Omega(X^-1) / Lambda'(X^-1)
+            let x = self.locator(p);
+            let x_inv = x.inv();
+            let denominator = derivative.evaluate(&x_inv);
+            if denominator == RsField::ZERO {
+                return Err(Error::Uncorrectable);
+            }
+            let scale = pow(x, (256 - self.first_root % 255) % 255);
+            let magnitude = scale * evaluator.evaluate(&x_inv) / denominator;
+            candidate[p] ^= magnitude.value() as u8;
+        }
+
+        if self.syndromes(&candidate)?.iter().any(|s| *s != RsField::ZERO) {
+            return Err(Error::Uncorrectable);
+        }
+        let changed = candidate.iter().zip(codeword.iter()).filter(|(a, b)| a != b).count();
+        codeword.copy_from_slice(&candidate);
+        Ok(changed)
+    }
+
+    /// Error locator `X = beta^(n - 1 - position)` of a byte position.
+    fn locator(&self, position: usize) -> RsField {
+        pow(self.beta, self.n - 1 - position)
+    }
+
+    /// Errors-and-erasures Berlekamp–Massey: starting from the erasure
+    /// locator, finds the shortest combined locator consistent with the
+    /// syndromes.
+    fn berlekamp_massey(
+        &self,
+        syndromes: &[RsField],
+        erasure_locator: Polynomial<RsField>,
+        erasure_count: usize,
+    ) -> Polynomial<RsField> {
+        let x = Polynomial::monomial(RsField::ONE, 1);
+        let mut locator = erasure_locator.clone();
+        let mut previous = erasure_locator;
+        let mut length = erasure_count;
+
+        for r in erasure_count..self.parity_len() {
+            let discrepancy = (0..=r)
+                .fold(RsField::ZERO, |acc, j| acc + locator.coefficient(j) * syndromes[r - j]);
+            previous = &previous * &x;
+
+            if discrepancy != RsField::ZERO {
+                let next = &locator - &previous.scalar_mul(&discrepancy);
+                if 2 * length <= r + erasure_count {
+                    previous = locator.scalar_mul(&discrepancy.inv());
+                    length = r + 1 + erasure_count - length;
+                }
+                locator = next;
+            }
+        }
+        locator
+    }
+
+    /// Positions `p` whose inverse locator is a root of `locator`.
+    fn chien_search(&self, locator: &Polynomial<RsField>) -> Vec<usize> {
+        (0..self.n).filter(|&p| locator.evaluate(&self.locator(p).inv()) == RsField::ZERO).collect()
+    }
+}
+
+/// In characteristic 2 only the odd-degree terms survive differentiation.
+fn formal_derivative(p: &Polynomial<RsField>) -> Polynomial<RsField> {
+    Polynomial::new(
+        p.coefficients()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| if i % 2 == 1 { c } else { RsField::ZERO })
+            .collect(),
+    )
+}
+
+fn pow(base: RsField, exp: usize) -> RsField {
+    RsField::new(gf2n_pow::<RS_POLY, 8>(base.value(), exp as u64))
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{seq::index::sample, Rng, RngCore};
+
+    #[test]
+    fn test_qr_version_1m_codeword() {
+        // "HELLO WORLD" as a version 1-M QR code: 16 data and 10 EC codewords
+        let rs = ReedSolomon::new(26, 16).unwrap();
+        let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+        let codeword = rs.encode(&data).unwrap();
+        assert_eq!(codeword[16..], [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+        assert!(rs.syndromes(&codeword).unwrap().iter().all(|s| *s == RsField::ZERO));
+    }
+
+    #[test]
+    fn test_corrects_errors_and_erasures_up_to_capacity() {
+        let mut rng = rand::thread_rng();
+        let codes = [(26, 16, 0, 1), (255, 223, 112, 11), (40, 8, 1, 2), (10, 1, 0, 1)];
+
+        for (n, k, first_root, step) in codes {
+            let rs = ReedSolomon::with_roots(n, k, first_root, step).unwrap();
+            for _ in 0..40 {
+                let mut data = vec![0u8; k];
+                rng.fill_bytes(&mut data);
+                let codeword = rs.encode(&data).unwrap();
+
+                let erasure_count = rng.gen_range(0..=n - k);
+                let error_count = rng.gen_range(0..=(n - k - erasure_count) / 2);
+                let positions = sample(&mut rng, n, erasure_count + error_count).into_vec();
+
+                let mut received = codeword.clone();
+                for &p in &positions {
+                    received[p] ^= rng.gen_range(1..=255u8);
+                }
+                let erasures = &positions[..erasure_count];
+                assert_eq!(rs.decode(&received, erasures), Ok(data));
+
+                let mut in_place = received.clone();
+                assert_eq!(rs.correct(&mut in_place, erasures), Ok(positions.len()));
+                assert_eq!(in_place, codeword);
+            }
+        }
+    }
+
+    #[test]
+    fn test_erasures_need_not_be_corrupted() {
+        let rs = ReedSolomon::new(20, 10).unwrap();
+        let codeword = rs.encode(&[7; 10]).unwrap();
+        assert_eq!(rs.decode(&codeword, &[0, 5, 19]), Ok(vec![7; 10]));
+
+        let mut received = codeword.clone();
+        received[3] ^= 0x40;
+        assert_eq!(rs.correct(&mut received, &[0, 3, 8]), Ok(1));
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn test_uncorrectable_leaves_codeword_untouched() {
+        let rs = ReedSolomon::new(20, 10).unwrap();
+        let codeword = rs.encode(&[1; 10]).unwrap();
+
+        let erasures: Vec<usize> = (0..11).collect();
+        assert_eq!(rs.decode(&codeword, &erasures), Err(Error::Uncorrectable));
+
+        // Far beyond capacity: either detected or decoded to another codeword
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut received = codeword.clone();
+            for p in sample(&mut rng, 20, 12) {
+                received[p] ^= rng.gen_range(1..=255u8);
+            }
+            let before = received.clone();
+            match rs.correct(&mut received, &[]) {
+                Err(e) => {
+                    assert_eq!(e, Error::Uncorrectable);
+                    assert_eq!(received, before);
+                }
+                Ok(_) => assert!(rs.syndromes(&received).unwrap().iter().all(|s| *s == RsField::ZERO)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_bad_parameters() {
+        assert_eq!(ReedSolomon::new(256, 200).unwrap_err(), Error::InvalidCodeParameters);
+        assert_eq!(ReedSolomon::new(10, 10).unwrap_err(), Error::InvalidCodeParameters);
+        assert_eq!(ReedSolomon::new(10, 0).unwrap_err(), Error::InvalidCodeParameters);
+        assert_eq!(ReedSolomon::with_roots(10, 5, 0, 3).unwrap_err(), Error::InvalidCodeParameters);
+
+        let rs = ReedSolomon::new(10, 5).unwrap();
+        assert_eq!(rs.encode(&[0; 4]), Err(Error::CodeLengthMismatch));
+        assert_eq!(rs.decode(&[0; 9], &[]), Err(Error::CodeLengthMismatch));
+        assert_eq!(rs.decode(&[0; 10], &[10]), Err(Error::InvalidErasure));
+        assert_eq!(rs.decode(&[0; 10], &[2, 2]), Err(Error::InvalidErasure));
+    }
+}
@@ -0,0 +1,247 @@
+pub mod feldman;
+
+use rand::{CryptoRng, RngCore, rngs::OsRng};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    polynomial::{
+        lagrange_interpolate_at_zero::try_lagrange_interpolate_at_zero,
+        InterpolationError,
+        Polynomial,
+    },
+    types::FieldElement,
+    Error,
+    Result,
+};
+
+/// One share of a secret split over GF(256).
+///
+/// `x` is the (non-zero) evaluation point shared by every byte of the secret,
+/// `y` holds one polynomial evaluation per secret byte.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct Share {
+    pub x: FieldElement,
+    pub y: Vec<FieldElement>,
+}
+
+impl Share {
+    /// Serializes the share as `x || y[0] || y[1] || ...`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.y.len() + 1);
+        out.push(self.x.0);
+        out.extend(self.y.iter().map(|b| b.0));
+        out
+    }
+
+    /// Parses a share produced by [`Share::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&x, y) = bytes.split_first()?;
+        if x == 0 { return None; }
+        Some(Share {
+            x: FieldElement(x),
+            y: y.iter().map(|&b| FieldElement(b)).collect(),
+        })
+    }
+}
+
+/// Splits `secret` into `shares` shares, any `threshold` of which recover it.
+///
+/// Uses the operating system RNG; see [`split_with_rng`] to supply your own.
+/// Panics on invalid parameters; see [`try_split`].
+pub fn split(secret: &[u8], threshold: u8, shares: u8) -> Vec<Share> {
+    try_split(secret, threshold, shares).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [`split`], but reports invalid parameters as an [`Error`].
+pub fn try_split(secret: &[u8], threshold: u8, shares: u8) -> Result<Vec<Share>> {
+    try_split_with_rng(secret, threshold, shares, &mut OsRng)
+}
+
+/// Splits `secret` using a fresh random polynomial of degree `threshold - 1`
+/// per byte, evaluated at the x-coordinates `1..=shares`.
+pub fn split_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+    rng: &mut R
+) -> Vec<Share> {
+    try_split_with_rng(secret, threshold, shares, rng).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [`split_with_rng`], but reports invalid parameters as an [`Error`].
+pub fn try_split_with_rng<R: RngCore + CryptoRng>(
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+    rng: &mut R
+) -> Result<Vec<Share>> {
+    if threshold == 0 {
+        return Err(Error::ThresholdZero);
+    }
+    if threshold > shares {
+        return Err(Error::ThresholdExceedsShares);
+    }
+
+    let mut out: Vec<Share> = (1..=shares)
+        .map(|x| Share { x: FieldElement(x), y: Vec::with_capacity(secret.len()) })
+        .collect();
+
+    for &byte in secret {
+        // The constant term is the secret byte, the rest are uniformly random
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(FieldElement(byte));
+        coefficients.extend((1..threshold).map(|_| FieldElement((rng.next_u32() & 0xFF) as u8)));
+        let mut polynomial = Polynomial::new(coefficients);
+
+        for share in out.iter_mut() {
+            share.y.push(polynomial.evaluate(&share.x));
+        }
+        polynomial.zeroize();
+    }
+
+    Ok(out)
+}
+
+/// Recombines `shares` into the original secret.
+///
+/// At least `threshold` shares with distinct x-coordinates must be supplied;
+/// fewer shares yield an unrelated value rather than an error. Panics on
+/// malformed input; see [`try_combine`].
+pub fn combine(shares: &[Share]) -> Vec<u8> {
+    try_combine(shares).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [`combine`], but reports missing, mismatched, duplicated or zero-x
+/// shares as an [`Error`]. Use this for shares received from untrusted parties.
+pub fn try_combine(shares: &[Share]) -> Result<Vec<u8>> {
+    let Some(first) = shares.first() else {
+        return Err(InterpolationError::NoPoints.into());
+    };
+    let len = first.y.len();
+    if shares.iter().any(|s| s.y.len() != len) {
+        return Err(Error::ShareLengthMismatch);
+    }
+    // Checked once up front so that empty secrets are validated too
+    for (first, share) in shares.iter().enumerate() {
+        if share.x == FieldElement::ZERO {
+            return Err(Error::ZeroShareX);
+        }
+        if let Some(offset) = shares[first + 1..].iter().position(|s| s.x == share.x) {
+            let second = first + 1 + offset;
+            return Err(InterpolationError::DuplicateX { first, second }.into());
+        }
+    }
+
+    let mut points = vec![(0u8, 0u8); shares.len()];
+    let mut secret = Vec::with_capacity(len);
+    for i in 0..len {
+        for (point, share) in points.iter_mut().zip(shares) {
+            *point = (share.x.0, share.y[i].0);
+        }
+        match try_lagrange_interpolate_at_zero(&points) {
+            Ok(byte) => secret.push(byte),
+            Err(e) => {
+                points.zeroize();
+                secret.zeroize();
+                return Err(e);
+            }
+        }
+    }
+    points.zeroize();
+
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_combine_roundtrip() {
+        let secret = b"correct horse battery staple";
+        let shares = split(secret, 3, 5);
+
+        assert_eq!(shares.len(), 5);
+        assert_eq!(combine(&shares[..3]), secret);
+        assert_eq!(combine(&shares[2..]), secret);
+        assert_eq!(combine(&shares), secret);
+    }
+
+    #[test]
+    fn test_any_subset_of_threshold_recovers() {
+        let secret = [0u8, 1, 2, 254, 255];
+        let shares = split(&secret, 2, 4);
+
+        for i in 0..shares.len() {
+            for j in (i + 1)..shares.len() {
+                let subset = [shares[i].clone(), shares[j].clone()];
+                assert_eq!(combine(&subset), secret);
+            }
+        }
+    }
+
+    #[test]
+    fn test_x_coordinates_are_distinct_and_non_zero() {
+        let shares = split(b"k", 2, 255);
+        let mut seen = [false; 256];
+        for share in &shares {
+            assert_ne!(share.x.0, 0);
+            assert!(!seen[share.x.0 as usize]);
+            seen[share.x.0 as usize] = true;
+        }
+    }
+
+    #[test]
+    fn test_threshold_one_is_replication() {
+        let shares = split(b"abc", 1, 3);
+        for share in &shares {
+            assert_eq!(share.y.iter().map(|b| b.0).collect::<Vec<_>>(), b"abc");
+        }
+    }
+
+    #[test]
+    fn test_share_bytes_roundtrip() {
+        let shares = split(b"secret", 2, 3);
+        let parsed: Vec<Share> = shares
+            .iter()
+            .map(|s| Share::from_bytes(&s.to_bytes()).unwrap())
+            .collect();
+        assert!(parsed == shares);
+        assert!(Share::from_bytes(&[]).is_none());
+        assert!(Share::from_bytes(&[0, 1, 2]).is_none());
+    }
+
+    #[test]
+    fn test_try_variants_reject_bad_input() {
+        assert_eq!(try_split(b"x", 0, 3).err(), Some(Error::ThresholdZero));
+        assert_eq!(try_split(b"x", 4, 3).err(), Some(Error::ThresholdExceedsShares));
+
+        let shares = split(b"secret", 2, 3);
+        assert_eq!(try_combine(&[]), Err(Error::Interpolation(InterpolationError::NoPoints)));
+        assert_eq!(
+            try_combine(&[shares[0].clone(), shares[0].clone()]),
+            Err(Error::Interpolation(InterpolationError::DuplicateX { first: 0, second: 1 }))
+        );
+
+        let mut short = shares[1].clone();
+        short.y.pop();
+        assert_eq!(try_combine(&[shares[0].clone(), short]), Err(Error::ShareLengthMismatch));
+        assert_eq!(try_combine(&shares[1..]), Ok(b"secret".to_vec()));
+
+        // Coordinates are validated even when there are no bytes to recover
+        let empty = split(b"", 2, 3);
+        assert_eq!(
+            try_combine(&[empty[1].clone(), empty[1].clone()]),
+            Err(Error::Interpolation(InterpolationError::DuplicateX { first: 0, second: 1 }))
+        );
+        let mut zero = empty[0].clone();
+        zero.x = FieldElement::ZERO;
+        assert_eq!(try_combine(&[zero, empty[1].clone()]), Err(Error::ZeroShareX));
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold cannot exceed the number of shares")]
+    fn test_threshold_above_shares_should_panic() {
+        split(b"x", 4, 3);
+    }
+}
@@ -0,0 +1,384 @@
+//! Feldman verifiable secret sharing over a prime-order subgroup of Z_p^*.
+//!
+//! The dealer shares `s` with a random polynomial `f` over Z_q, `f(0) = s`,
+//! and publishes the commitments `C_i = g^(a_i) mod p` to its coefficients.
+//! The holder of share `(x, y)` checks `g^y == prod C_i^(x^i)`, which catches
+//! a dealer handing out shares that do not lie on one polynomial. The
+//! commitments reveal `g^s`, so the secret should be high-entropy.
+//!
+//! Exponentiations by secrets (the coefficients when committing, and the
+//! share value when verifying) use a constant-time Montgomery ladder over
+//! every bit of `q`. Public exponents use the faster variable-time
+//! `BigUint::modpow`. Everything else, such as evaluating the polynomial and
+//! reducing mod `q`, is `BigUint` arithmetic, whose timing can depend on
+//! operand sizes.
+//!
+//! Secret values are held in [`SecureBigUint`], but `BigUint` offers no way
+//! to overwrite its limbs. "Zeroizing" the coefficients and shares therefore
+//! only replaces them with zero and frees the old buffers unwiped; the same
+//! goes for the temporaries of the `BigUint` arithmetic. Do not rely on
+//! secrets being erased from memory.
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    polynomial::{evaluate_polynomial::secure_evaluate_polynomial, modinv::try_modinv, InterpolationError},
+    types::{secure_types::SecureBigUint, MontgomeryField},
+    Error,
+    Result,
+};
+
+/// The 2048-bit MODP group of RFC 3526, section 3.
+const RFC3526_2048: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF",
+);
+
+/// The 3072-bit MODP group of RFC 3526, section 4.
+const RFC3526_3072: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33",
+    "A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864",
+    "D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2",
+    "08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF",
+);
+
+/// Largest supported modulus, in bits.
+const MAX_MODULUS_BITS: u64 = 8192;
+
+/// A safe prime `p = 2q + 1` and a generator `g` of the subgroup of order `q`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupParams {
+    p: BigUint,
+    q: BigUint,
+    g: BigUint,
+}
+
+impl GroupParams {
+    /// Checks that `g` generates the order-`q` subgroup, `q = (p - 1) / 2`.
+    ///
+    /// Like [`PrimeModulus`](crate::types::PrimeModulus), `p` and `q` are
+    /// trusted to be prime; they are not tested for primality. `p` may have
+    /// at most 8192 bits.
+    pub fn new(p: BigUint, g: BigUint) -> Result<Self> {
+        if p < BigUint::from(7u32) || !p.bit(0) || p.bits() > MAX_MODULUS_BITS {
+            return Err(Error::InvalidGroupParameters);
+        }
+        let q = &p >> 1;
+        if g <= BigUint::one() || g >= p || !g.modpow(&q, &p).is_one() {
+            return Err(Error::InvalidGroupParameters);
+        }
+        Ok(GroupParams { p, q, g })
+    }
+
+    /// RFC 3526 group 14 (2048-bit safe prime) with `g = 2`, which is a
+    /// quadratic residue since `p = 7 mod 8`.
+    pub fn rfc3526_2048() -> Self {
+        Self::from_hex(RFC3526_2048)
+    }
+
+    /// RFC 3526 group 15 (3072-bit safe prime) with `g = 2`.
+    pub fn rfc3526_3072() -> Self {
+        Self::from_hex(RFC3526_3072)
+    }
+
+    fn from_hex(p: &str) -> Self {
+        let p = BigUint::parse_bytes(p.as_bytes(), 16).expect("RFC 3526 primes are valid hex");
+        Self::new(p, BigUint::from(2u32)).expect("RFC 3526 groups are safe-prime groups")
+    }
+
+    pub fn p(&self) -> &BigUint {
+        &self.p
+    }
+
+    /// Order of the subgroup; secrets and shares live in Z_q.
+    pub fn q(&self) -> &BigUint {
+        &self.q
+    }
+
+    pub fn g(&self) -> &BigUint {
+        &self.g
+    }
+
+    /// `g^exp mod p` for a secret `exp < q`, in time independent of `exp`.
+    fn pow_secret(&self, exp: &BigUint) -> BigUint {
+        match self.p.bits() {
+            0..=2048 => self.pow_secret_limbs::<32>(exp),
+            2049..=3072 => self.pow_secret_limbs::<48>(exp),
+            3073..=4096 => self.pow_secret_limbs::<64>(exp),
+            _ => self.pow_secret_limbs::<128>(exp),
+        }
+    }
+
+    fn pow_secret_limbs<const LIMBS: usize>(&self, exp: &BigUint) -> BigUint {
+        let field = MontgomeryField::<LIMBS>::new(&self.p).expect("p is odd and fits in LIMBS limbs");
+        let g = field.from_biguint(&self.g).expect("g < p");
+        field.to_biguint(&field.pow_bits(&g, exp, self.q.bits()))
+    }
+
+    fn is_subgroup_element(&self, c: &BigUint) -> bool {
+        !c.is_zero() && c < &self.p && c.modpow(&self.q, &self.p).is_one()
+    }
+}
+
+/// One share `(x, f(x) mod q)` of a Feldman dealing.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct VerifiableShare {
+    pub x: u64,
+    pub y: SecureBigUint,
+}
+
+/// The output of [`split`]: one share per participant and the public
+/// commitments every participant verifies against.
+#[derive(Clone)]
+pub struct Dealing {
+    pub shares: Vec<VerifiableShare>,
+    /// `g^(a_i) mod p` for each coefficient; its length is the threshold.
+    pub commitments: Vec<BigUint>,
+}
+
+/// Splits `secret` (an element of Z_q) into `shares` verifiable shares, any
+/// `threshold` of which recover it, using the operating system RNG.
+pub fn split(params: &GroupParams, secret: &BigUint, threshold: usize, shares: usize) -> Result<Dealing> {
+    split_with_rng(params, secret, threshold, shares, &mut OsRng)
+}
+
+/// Like [`split`], with a caller-supplied RNG.
+pub fn split_with_rng<R: RngCore + CryptoRng>(
+    params: &GroupParams,
+    secret: &BigUint,
+    threshold: usize,
+    shares: usize,
+    rng: &mut R,
+) -> Result<Dealing> {
+    if threshold == 0 {
+        return Err(Error::ThresholdZero);
+    }
+    if threshold > shares {
+        return Err(Error::ThresholdExceedsShares);
+    }
+    if secret >= &params.q {
+        return Err(Error::SecretOutOfRange);
+    }
+    if BigUint::from(shares) >= params.q {
+        return Err(Error::InvalidGroupParameters);
+    }
+
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(SecureBigUint(secret.clone()));
+    coefficients.extend((1..threshold).map(|_| SecureBigUint(rng.gen_biguint_below(&params.q))));
+
+    let commitments = coefficients.iter().map(|a| params.pow_secret(&a.0)).collect();
+    let shares = (1..=shares as u64)
+        .map(|x| VerifiableShare {
+            x,
+            y: SecureBigUint(secure_evaluate_polynomial(&coefficients, &BigUint::from(x), &params.q)),
+        })
+        .collect();
+    coefficients.zeroize();
+
+    Ok(Dealing { shares, commitments })
+}
+
+/// Checks `share` against the dealer's `commitments`.
+///
+/// Fails with [`Error::InvalidCommitment`] if a commitment lies outside the
+/// order-`q` subgroup, and [`Error::InvalidShare`] if the share is not on the
+/// committed polynomial.
+pub fn verify_share(params: &GroupParams, commitments: &[BigUint], share: &VerifiableShare) -> Result<()> {
+    if commitments.is_empty() || !commitments.iter().all(|c| params.is_subgroup_element(c)) {
+        return Err(Error::InvalidCommitment);
+    }
+    if share.x == 0 || share.y.0 >= params.q {
+        return Err(Error::InvalidShare);
+    }
+
+    // prod C_i^(x^i), with the exponents reduced mod q
+    let x = BigUint::from(share.x);
+    let mut power = BigUint::one();
+    let mut expected = BigUint::one();
+    for c in commitments {
+        expected = expected * c.modpow(&power, &params.p) % &params.p;
+        power = power * &x % &params.q;
+    }
+
+    if params.pow_secret(&share.y.0) != expected {
+        return Err(Error::InvalidShare);
+    }
+    Ok(())
+}
+
+/// Recombines the secret from shares with distinct x-coordinates by Lagrange
+/// interpolation at zero over Z_q.
+///
+/// As with plain Shamir, fewer than `threshold` shares yield an unrelated
+/// value; see [`combine_verified`] to check the shares first.
+pub fn combine(params: &GroupParams, shares: &[VerifiableShare]) -> Result<BigUint> {
+    if shares.is_empty() {
+        return Err(InterpolationError::NoPoints.into());
+    }
+    for (first, share) in shares.iter().enumerate() {
+        if let Some(offset) = shares[first + 1..].iter().position(|s| s.x == share.x) {
+            return Err(InterpolationError::DuplicateX { first, second: first + 1 + offset }.into());
+        }
+    }
+
+    let q = &params.q;
+    let mut secret = BigUint::zero();
+    for share in shares {
+        let xi = BigUint::from(share.x);
+        let mut num = BigUint::one();
+        let mut den = BigUint::one();
+        for other in shares.iter().filter(|s| s.x != share.x) {
+            let xj = BigUint::from(other.x);
+            num = num * &xj % q;
+            den = den * ((&xj + q - &xi % q) % q) % q;
+        }
+        let li = num * try_modinv(&den, q)? % q;
+        secret = (secret + li * &share.y.0) % q;
+    }
+    Ok(secret)
+}
+
+/// Verifies every share against `commitments` and that there are at least
+/// `threshold = commitments.len()` of them, then recombines the secret.
+pub fn combine_verified(params: &GroupParams, commitments: &[BigUint], shares: &[VerifiableShare]) -> Result<BigUint> {
+    for share in shares {
+        verify_share(params, commitments, share)?;
+    }
+    if shares.len() < commitments.len() {
+        return Err(Error::ThresholdExceedsShares);
+    }
+    combine(params, shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// p = 2039 = 2 * 1019 + 1, with 4 = 2^2 generating the order-1019 subgroup.
+    fn small_group() -> GroupParams {
+        GroupParams::new(2039u32.into(), 4u32.into()).unwrap()
+    }
+
+    #[test]
+    fn test_split_verify_combine_roundtrip() {
+        let params = small_group();
+        let secret = BigUint::from(777u32);
+        let dealing = split(&params, &secret, 3, 5).unwrap();
+
+        assert_eq!(dealing.commitments.len(), 3);
+        assert_eq!(dealing.commitments[0], params.g().modpow(&secret, params.p()));
+        for share in &dealing.shares {
+            assert_eq!(verify_share(&params, &dealing.commitments, share), Ok(()));
+        }
+        assert_eq!(combine(&params, &dealing.shares[..3]), Ok(secret.clone()));
+        assert_eq!(combine(&params, &dealing.shares[2..]), Ok(secret.clone()));
+        assert_eq!(combine_verified(&params, &dealing.commitments, &dealing.shares[1..4]), Ok(secret));
+    }
+
+    #[test]
+    fn test_rfc3526_group() {
+        let params = GroupParams::rfc3526_2048();
+        assert_eq!(params.p().bits(), 2048);
+        assert_eq!(GroupParams::new(params.p().clone(), 2u32.into()), Ok(params.clone()));
+        assert_eq!(GroupParams::rfc3526_3072().p().bits(), 3072);
+
+        let secret = OsRng.gen_biguint_below(params.q());
+        let dealing = split(&params, &secret, 2, 3).unwrap();
+        assert_eq!(combine_verified(&params, &dealing.commitments, &dealing.shares[1..]), Ok(secret));
+    }
+
+    #[test]
+    fn test_secret_exponentiation_matches_modpow() {
+        for params in [small_group(), GroupParams::rfc3526_2048(), GroupParams::rfc3526_3072()] {
+            let q = params.q();
+            for exp in [BigUint::zero(), q - 1u32, OsRng.gen_biguint_below(q)] {
+                assert_eq!(params.pow_secret(&exp), params.g().modpow(&exp, params.p()));
+            }
+        }
+
+        // The wider limb counts used for larger moduli
+        let params = small_group();
+        for exp in [BigUint::zero(), params.q() - 1u32, OsRng.gen_biguint_below(params.q())] {
+            let expected = params.g().modpow(&exp, params.p());
+            assert_eq!(params.pow_secret_limbs::<64>(&exp), expected);
+            assert_eq!(params.pow_secret_limbs::<128>(&exp), expected);
+        }
+    }
+
+    #[test]
+    fn test_detects_inconsistent_dealer() {
+        let params = small_group();
+        let mut dealing = split(&params, &BigUint::from(5u32), 2, 4).unwrap();
+
+        // A share off the committed polynomial
+        dealing.shares[1].y.0 = (&dealing.shares[1].y.0 + 1u32) % params.q();
+        assert_eq!(verify_share(&params, &dealing.commitments, &dealing.shares[1]), Err(Error::InvalidShare));
+        assert_eq!(verify_share(&params, &dealing.commitments, &dealing.shares[0]), Ok(()));
+        assert_eq!(
+            combine_verified(&params, &dealing.commitments, &dealing.shares),
+            Err(Error::InvalidShare)
+        );
+
+        // A commitment to a different polynomial
+        dealing.commitments[1] = dealing.commitments[1].clone() * params.g() % params.p();
+        assert_eq!(verify_share(&params, &dealing.commitments, &dealing.shares[0]), Err(Error::InvalidShare));
+    }
+
+    #[test]
+    fn test_rejects_commitments_outside_subgroup() {
+        let params = small_group();
+        let dealing = split(&params, &BigUint::from(5u32), 2, 2).unwrap();
+        let share = &dealing.shares[0];
+
+        // p - 1 has order 2, and 0 is not in the group at all
+        for bad in [params.p() - 1u32, BigUint::zero(), params.p().clone()] {
+            let commitments = [dealing.commitments[0].clone(), bad];
+            assert_eq!(verify_share(&params, &commitments, share), Err(Error::InvalidCommitment));
+        }
+        assert_eq!(verify_share(&params, &[], share), Err(Error::InvalidCommitment));
+    }
+
+    #[test]
+    fn test_parameter_errors() {
+        let params = small_group();
+        assert_eq!(GroupParams::new(2039u32.into(), 2038u32.into()), Err(Error::InvalidGroupParameters));
+        assert_eq!(GroupParams::new(2039u32.into(), 1u32.into()), Err(Error::InvalidGroupParameters));
+        assert_eq!(GroupParams::new(2040u32.into(), 4u32.into()), Err(Error::InvalidGroupParameters));
+        let too_wide = (BigUint::one() << (MAX_MODULUS_BITS + 1)) - 1u32;
+        assert_eq!(GroupParams::new(too_wide, 4u32.into()), Err(Error::InvalidGroupParameters));
+
+        let secret = BigUint::from(1u32);
+        assert_eq!(split(&params, &secret, 0, 3).err(), Some(Error::ThresholdZero));
+        assert_eq!(split(&params, &secret, 4, 3).err(), Some(Error::ThresholdExceedsShares));
+        assert_eq!(split(&params, params.q(), 2, 3).err(), Some(Error::SecretOutOfRange));
+
+        let dealing = split(&params, &secret, 3, 3).unwrap();
+        assert_eq!(
+            combine_verified(&params, &dealing.commitments, &dealing.shares[..2]),
+            Err(Error::ThresholdExceedsShares)
+        );
+        let duplicated = [dealing.shares[0].clone(), dealing.shares[0].clone()];
+        assert_eq!(
+            combine(&params, &duplicated),
+            Err(InterpolationError::DuplicateX { first: 0, second: 1 }.into())
+        );
+    }
+}
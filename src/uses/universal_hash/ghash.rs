@@ -0,0 +1,130 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(target_arch = "x86_64")]
+use crate::galois_fields::gf2_128::clmul;
+use crate::{galois_fields::gf2_128::gcm_mul_ct, uses::universal_hash::BlockBuffer};
+
+/// Streaming GHASH (NIST SP 800-38D, section 6.4) keyed by the hash subkey `H`.
+///
+/// Input passed to [`Ghash::update`] is concatenated; [`Ghash::update_padded`]
+/// additionally zero-pads to a block boundary, which is how GCM separates the
+/// associated data from the ciphertext.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Ghash {
+    h: u128,
+    y: u128,
+    buffer: BlockBuffer,
+    /// Whether PCLMULQDQ was detected when the hash was created.
+    clmul: bool,
+}
+
+impl Ghash {
+    pub fn new(h: [u8; 16]) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let clmul = clmul::is_supported();
+        #[cfg(not(target_arch = "x86_64"))]
+        let clmul = false;
+        Ghash { h: u128::from_be_bytes(h), y: 0, buffer: BlockBuffer::default(), clmul }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let (h, y, clmul) = (self.h, &mut self.y, self.clmul);
+        self.buffer.update(data, |block| {
+            *y = mul(*y ^ u128::from_be_bytes(block), h, clmul);
+        });
+    }
+
+    pub fn update_padded(&mut self, data: &[u8]) {
+        self.update(data);
+        let (h, y, clmul) = (self.h, &mut self.y, self.clmul);
+        self.buffer.pad(|block| {
+            *y = mul(*y ^ u128::from_be_bytes(block), h, clmul);
+        });
+    }
+
+    /// Pads any trailing partial block and returns the digest.
+    pub fn finalize(mut self) -> [u8; 16] {
+        self.update_padded(&[]);
+        self.y.to_be_bytes()
+    }
+}
+
+#[inline]
+fn mul(a: u128, b: u128, clmul: bool) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if clmul {
+        // SAFETY: `clmul` is only set when the CPU supports PCLMULQDQ
+        return unsafe { clmul::gcm_mul_clmul(a, b) };
+    }
+    let _ = clmul;
+    gcm_mul_ct(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ghash_gcm_test_case_2() {
+        // SP 800-38D / McGrew–Viega test case 2: K = 0, IV = 0, P = 0^128
+        let h = 0x66e9_4bd4_ef8a_2c3b_884c_fa59_ca34_2b2eu128.to_be_bytes();
+        let c = 0x0388_dace_60b6_a392_f328_c2b9_71b2_fe78u128.to_be_bytes();
+        let lengths = 128u128.to_be_bytes(); // len(A) = 0 bits, len(C) = 128 bits
+
+        let mut ghash = Ghash::new(h);
+        ghash.update_padded(&[]);
+        ghash.update_padded(&c);
+        ghash.update(&lengths);
+        assert_eq!(
+            ghash.finalize(),
+            0xf38c_bb1a_d692_23dc_c345_7ae5_b6b0_f885u128.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let h = [0x42u8; 16];
+        let data: Vec<u8> = (0..77u8).collect();
+
+        let mut one_shot = Ghash::new(h);
+        one_shot.update(&data);
+
+        let mut streamed = Ghash::new(h);
+        for chunk in data.chunks(5) {
+            streamed.update(chunk);
+        }
+        assert_eq!(one_shot.finalize(), streamed.finalize());
+    }
+
+    #[test]
+    fn test_padding_separates_inputs() {
+        let h = [0x42u8; 16];
+        let mut joined = Ghash::new(h);
+        joined.update(b"abc");
+        joined.update(b"def");
+
+        let mut padded = Ghash::new(h);
+        padded.update_padded(b"abc");
+        padded.update(b"def");
+        assert_ne!(joined.finalize(), padded.finalize());
+    }
+
+    #[test]
+    fn test_clmul_matches_portable_on_random_inputs() {
+        use rand::{Rng, RngCore};
+
+        let mut rng = rand::thread_rng();
+        for len in 0..100 {
+            let h: [u8; 16] = rng.r#gen();
+            let mut data = vec![0u8; len];
+            rng.fill_bytes(&mut data);
+
+            let mut dispatched = Ghash::new(h);
+            let mut portable = Ghash::new(h);
+            portable.clmul = false;
+            dispatched.update(&data);
+            portable.update(&data);
+            assert_eq!(dispatched.finalize(), portable.finalize());
+        }
+    }
+}
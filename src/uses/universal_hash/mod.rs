@@ -0,0 +1,50 @@
+pub mod ghash;
+pub use ghash::Ghash;
+pub mod polyval;
+pub use polyval::Polyval;
+
+use zeroize::Zeroize;
+
+/// Collects input into 16-byte blocks for the streaming hashers.
+#[derive(Clone, Default, Zeroize)]
+pub(crate) struct BlockBuffer {
+    buf: [u8; 16],
+    len: usize,
+}
+
+impl BlockBuffer {
+    /// Feeds `data`, calling `f` once for every completed block.
+    pub(crate) fn update(&mut self, mut data: &[u8], mut f: impl FnMut([u8; 16])) {
+        if self.len > 0 {
+            let take = (16 - self.len).min(data.len());
+            self.buf[self.len..self.len + take].copy_from_slice(&data[..take]);
+            self.len += take;
+            data = &data[take..];
+            if self.len < 16 { return; }
+            f(self.buf);
+            self.len = 0;
+        }
+
+        let mut chunks = data.chunks_exact(16);
+        for chunk in &mut chunks {
+            f(chunk.try_into().expect("chunk should be 16 bytes"));
+        }
+
+        let rest = chunks.remainder();
+        self.buf[..rest.len()].copy_from_slice(rest);
+        self.len = rest.len();
+    }
+
+    /// Bytes of the partially filled block, not yet passed to a callback.
+    pub(crate) fn pending(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Zero-pads and emits any partially filled block.
+    pub(crate) fn pad(&mut self, f: impl FnOnce([u8; 16])) {
+        if self.len == 0 { return; }
+        self.buf[self.len..].fill(0);
+        f(self.buf);
+        self.len = 0;
+    }
+}
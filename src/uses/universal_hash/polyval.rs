@@ -0,0 +1,65 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    galois_fields::gf2_128::{polyval_mul_ct, POLYVAL_X_INV_128},
+    uses::universal_hash::BlockBuffer,
+};
+
+/// Streaming POLYVAL (RFC 8452, section 3) keyed by `H`.
+///
+/// `S_j = dot(S_{j-1} + X_j, H)`; the `x^-128` factor of `dot` is folded into
+/// the key once at construction so each block costs a single multiply.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Polyval {
+    h: u128,
+    s: u128,
+    buffer: BlockBuffer,
+}
+
+impl Polyval {
+    pub fn new(h: [u8; 16]) -> Self {
+        let h = polyval_mul_ct(u128::from_le_bytes(h), POLYVAL_X_INV_128);
+        Polyval { h, s: 0, buffer: BlockBuffer::default() }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let (h, s) = (self.h, &mut self.s);
+        self.buffer.update(data, |block| {
+            *s = polyval_mul_ct(*s ^ u128::from_le_bytes(block), h);
+        });
+    }
+
+    pub fn update_padded(&mut self, data: &[u8]) {
+        self.update(data);
+        let (h, s) = (self.h, &mut self.s);
+        self.buffer.pad(|block| {
+            *s = polyval_mul_ct(*s ^ u128::from_le_bytes(block), h);
+        });
+    }
+
+    /// Pads any trailing partial block and returns the digest.
+    pub fn finalize(mut self) -> [u8; 16] {
+        self.update_padded(&[]);
+        self.s.to_le_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::array;
+
+    #[test]
+    fn test_polyval_rfc8452_appendix_a() {
+        let mut polyval = Polyval::new(array("25629347589242761d31f826ba4b757b"));
+        polyval.update(&array::<16>("4f4f95668c83dfb6401762bb2d01a262"));
+        polyval.update(&array::<16>("d1a24ddd2721d006bbe45f20d3c9f362"));
+        assert_eq!(polyval.finalize(), array("f7a3b47b846119fae5b7866cf5e5b77e"));
+    }
+
+    #[test]
+    fn test_empty_input_is_zero() {
+        let polyval = Polyval::new(array("25629347589242761d31f826ba4b757b"));
+        assert_eq!(polyval.finalize(), [0u8; 16]);
+    }
+}